@@ -0,0 +1,150 @@
+//! Implementation of the [`VecMinBuilder`] for incrementally constructing a [`VecMin`].
+
+use alloc::vec::Vec;
+
+use crate::VecMin;
+use crate::vec::ConstructError;
+
+/// A builder that accumulates elements one at a time before becoming a [`VecMin`].
+///
+/// The builder preallocates `M` elements and reports how many more are required to satisfy the minimum.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VecMinBuilder<T, const M: usize> {
+    vec: Vec<T>,
+}
+
+impl<T, const M: usize> VecMinBuilder<T, M> {
+    /// Creates a new empty builder with a capacity of `M`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_capacity(M)
+    }
+
+    /// Creates a new empty builder with a capacity of at least `capacity` and `M`.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            vec: Vec::with_capacity(capacity.max(M)),
+        }
+    }
+
+    /// See [`Vec::push`].
+    #[inline]
+    pub fn push(&mut self, item: T) {
+        self.vec.push(item);
+    }
+
+    /// Returns the number of elements pushed so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns `true` if no elements have been pushed.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Returns a slice of the elements pushed so far.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.vec.as_slice()
+    }
+
+    /// Returns the number of elements still required to reach the minimum length `M`.
+    #[inline]
+    pub fn remaining_to_min(&self) -> usize {
+        M.saturating_sub(self.vec.len())
+    }
+
+    /// Returns `true` if the builder has at least `M` elements.
+    #[inline]
+    pub fn is_satisfied(&self) -> bool {
+        self.vec.len() >= M
+    }
+
+    /// Builds the `VecMin`, returning an error if there are fewer than `M` elements.
+    ///
+    /// The builder can be resumed by converting the error back with [`VecMinBuilder::from`].
+    #[inline]
+    pub fn try_build(self) -> Result<VecMin<T, M>, ConstructError<T, M>> {
+        VecMin::try_from_vec(self.vec)
+    }
+
+    /// Builds the `VecMin`, filling it to `M` with elements generated by `f` if there are too few.
+    #[inline]
+    pub fn build_or_fill_with(self, f: impl FnMut() -> T) -> VecMin<T, M> {
+        VecMin::from_vec_padded_with(self.vec, f)
+    }
+
+    /// Builds the `VecMin` without checking the length.
+    ///
+    /// # Safety
+    /// - The builder must have at least `M` elements.
+    #[inline]
+    pub unsafe fn build_unchecked(self) -> VecMin<T, M> {
+        // Safety: Upheld by the caller.
+        unsafe { VecMin::from_vec_unchecked(self.vec) }
+    }
+
+    /// Returns the inner `Vec`, consuming the builder.
+    #[inline]
+    pub fn into_inner(self) -> Vec<T> {
+        self.vec
+    }
+}
+
+impl<T, const M: usize> Default for VecMinBuilder<T, M> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const M: usize> Extend<T> for VecMinBuilder<T, M> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.vec.extend(iter);
+    }
+}
+
+impl<T, const M: usize> From<ConstructError<T, M>> for VecMinBuilder<T, M> {
+    #[inline]
+    fn from(error: ConstructError<T, M>) -> Self {
+        let mut vec = error.0;
+        vec.reserve(M.saturating_sub(vec.len()));
+        Self { vec }
+    }
+}
+
+impl<T, const M: usize> From<VecMin<T, M>> for VecMinBuilder<T, M> {
+    #[inline]
+    fn from(vec_min: VecMin<T, M>) -> Self {
+        Self {
+            vec: vec_min.into_inner(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VecMinBuilder;
+
+    #[test]
+    fn builder() {
+        let mut builder = VecMinBuilder::<_, 3>::new();
+        assert!(builder.vec.capacity() >= 3);
+
+        builder.push(1);
+        assert_eq!(builder.remaining_to_min(), 2);
+
+        let mut builder = VecMinBuilder::from(builder.try_build().unwrap_err());
+        builder.extend([2, 3]);
+        assert!(builder.is_satisfied());
+        assert_eq!(builder.try_build().unwrap(), [1, 2, 3].to_vec());
+
+        let v = VecMinBuilder::<_, 2>::new().build_or_fill_with(|| 0);
+        assert_eq!(v, [0, 0].to_vec());
+    }
+}
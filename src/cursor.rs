@@ -0,0 +1,195 @@
+//! Implementation of [`CursorMut`] for in-place editing of a [`VecMin`].
+
+use alloc::vec::Vec;
+use core::mem;
+
+use crate::{ModifyError, VecMin};
+
+impl<T, const M: usize> VecMin<T, M> {
+    /// Returns a cursor pointing at the first element of the vector, or the "ghost" non-element if it is empty.
+    #[inline]
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, T, M> {
+        CursorMut {
+            vec_min: self,
+            index: 0,
+        }
+    }
+}
+
+/// A cursor over a [`VecMin`] with editing operations that keep the minimum length.
+///
+/// Modeled after `LinkedList`'s `CursorMut`, the cursor points at an element or at a "ghost" non-element past the
+/// last element, moving through the ghost when wrapping around either end.
+#[derive(Debug)]
+pub struct CursorMut<'a, T, const M: usize> {
+    vec_min: &'a mut VecMin<T, M>,
+    index: usize,
+}
+
+impl<T, const M: usize> CursorMut<'_, T, M> {
+    /// Returns the index of the current element, or `None` if the cursor is at the ghost.
+    #[inline]
+    pub fn index(&self) -> Option<usize> {
+        (!self.is_ghost()).then_some(self.index)
+    }
+
+    #[inline]
+    fn is_ghost(&self) -> bool {
+        self.index == self.vec_min.len()
+    }
+
+    /// Returns a reference to the current element, or `None` if the cursor is at the ghost.
+    #[inline]
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.vec_min.get_mut(self.index)
+    }
+
+    /// Returns a reference to the next element, or `None` if there is none.
+    #[inline]
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = if self.is_ghost() { 0 } else { self.index + 1 };
+        self.vec_min.get_mut(next)
+    }
+
+    /// Returns a reference to the previous element, or `None` if there is none.
+    #[inline]
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let prev = self.index.checked_sub(1)?;
+        self.vec_min.get_mut(prev)
+    }
+
+    /// Returns a read-only reference to the underlying `VecMin`.
+    #[inline]
+    pub fn as_vec_min(&self) -> &VecMin<T, M> {
+        self.vec_min
+    }
+
+    /// Moves the cursor to the next element, or to the ghost after the last element, or to the first element from the ghost.
+    #[inline]
+    pub fn move_next(&mut self) {
+        if self.is_ghost() {
+            self.index = 0;
+        } else {
+            self.index += 1;
+        }
+    }
+
+    /// Moves the cursor to the previous element, or to the ghost before the first element, or to the last element from the ghost.
+    #[inline]
+    pub fn move_prev(&mut self) {
+        if self.index == 0 {
+            self.index = self.vec_min.len();
+        } else {
+            self.index -= 1;
+        }
+    }
+
+    /// Inserts an element before the current element, the cursor keeps pointing at the current element.
+    ///
+    /// If the cursor is at the ghost the element is inserted at the back.
+    #[inline]
+    pub fn insert_before(&mut self, element: T) {
+        self.vec_min.insert(self.index, element);
+        self.index += 1;
+    }
+
+    /// Inserts an element after the current element, the cursor keeps pointing at the current element.
+    ///
+    /// If the cursor is at the ghost the element is inserted at the front.
+    #[inline]
+    pub fn insert_after(&mut self, element: T) {
+        if self.is_ghost() {
+            self.vec_min.insert(0, element);
+            self.index += 1;
+        } else {
+            self.vec_min.insert(self.index + 1, element);
+        }
+    }
+
+    /// Replaces the current element, returning the previous one, or returning `element` as an error if the cursor is at the ghost.
+    #[inline]
+    pub fn replace(&mut self, element: T) -> Result<T, T> {
+        match self.current() {
+            Some(current) => Ok(mem::replace(current, element)),
+            None => Err(element),
+        }
+    }
+
+    /// Removes the current element and moves the cursor to the next element.
+    ///
+    /// Returns `Ok(None)` if the cursor is at the ghost, or an error if the operation would reduce the length of the
+    /// vector below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn remove_current(&mut self) -> Result<Option<T>, ModifyError<M>> {
+        if self.is_ghost() {
+            Ok(None)
+        } else {
+            self.vec_min.remove(self.index).map(Some)
+        }
+    }
+
+    /// Splits off the elements after the current element, returning them in a `Vec`.
+    ///
+    /// If the cursor is at the ghost all elements are split off. Returns an error if the operation would reduce the
+    /// length of the vector below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn split_after(&mut self) -> Result<Vec<T>, ModifyError<M>> {
+        let at = if self.is_ghost() { 0 } else { self.index + 1 };
+        let tail = self.vec_min.split_off(at)?;
+
+        if at == 0 {
+            self.index = 0;
+        }
+        Ok(tail)
+    }
+
+    /// Splits off the elements before the current element, returning them in a `Vec`.
+    ///
+    /// If the cursor is at the ghost all elements are split off. Returns an error if the operation would reduce the
+    /// length of the vector below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn split_before(&mut self) -> Result<Vec<T>, ModifyError<M>> {
+        let head = self.vec_min.drain(..self.index)?.collect();
+
+        self.index = 0;
+        Ok(head)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use crate::{ModifyError, vecmin};
+
+    #[test]
+    fn cursor_mut() {
+        let mut v = vecmin![2; [1, 2, 3, 4]];
+        let mut cursor = v.cursor_mut();
+
+        while let Some(x) = cursor.current() {
+            if *x % 2 == 0 {
+                cursor.remove_current().unwrap();
+            } else {
+                cursor.move_next();
+            }
+        }
+        assert_eq!(cursor.as_vec_min(), &vec![1, 3]);
+
+        cursor.move_prev();
+        assert_eq!(cursor.remove_current(), Err(ModifyError));
+        assert_eq!(cursor.replace(5), Ok(3));
+
+        cursor.insert_before(2);
+        cursor.insert_after(6);
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.as_vec_min(), &vec![1, 2, 5, 6]);
+
+        assert_eq!(cursor.split_before(), Ok(vec![1, 2]));
+        assert_eq!(cursor.split_after(), Err(ModifyError));
+        assert_eq!(cursor.current(), Some(&mut 5));
+    }
+}
@@ -0,0 +1,1008 @@
+//! Implementation of the [`DynVecMin`] vector with a minimum length chosen at runtime.
+
+use alloc::boxed::Box;
+use alloc::collections::TryReserveError;
+use alloc::vec::{self, Vec};
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut, RangeBounds};
+use core::slice;
+
+use crate::vec::{self as vec_min, RawExtractIf};
+use crate::{DynModifyError, VecMin};
+
+/// A vector with a minimum length chosen at runtime.
+///
+/// Mirrors the API of [`VecMin`] for minimums that are not known at compile time, such as those read from
+/// configuration. Methods that reduce the length of the vector return a [`DynModifyError`] if the operation would
+/// reduce the length of the vector below the minimum.
+///
+/// Operations of [`VecMin`] that rely on the minimum being a constant are not provided, such as array accessors, splits
+/// into other `VecMin`s, and `TryFrom` conversions from slices and arrays, which have no way to receive the minimum.
+/// Use the constructors taking a minimum instead.
+#[derive(Clone, Debug)]
+pub struct DynVecMin<T> {
+    vec: Vec<T>,
+    min: usize,
+}
+
+// --- Custom ---
+impl<T> DynVecMin<T> {
+    /// Assertion that that the length of the vector is at least the minimum.
+    #[inline]
+    #[track_caller]
+    pub const fn assert_invariant(&self) {
+        assert!(self.vec.len() >= self.min);
+    }
+
+    /// Debug assertion that that the length of the vector is at least the minimum.
+    #[inline]
+    #[track_caller]
+    pub const fn debug_assert_invariant(&self) {
+        debug_assert!(self.vec.len() >= self.min);
+    }
+
+    /// Returns the minimum length of the vector.
+    #[inline]
+    pub const fn minimum(&self) -> usize {
+        self.min
+    }
+
+    /// Returns `true` if the length of the vector is equal to the minimum length.
+    #[inline]
+    pub const fn is_minimum(&self) -> bool {
+        self.vec.len() == self.min
+    }
+
+    /// Changes the minimum length of the vector, returning an error if the vector is shorter than `min`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn set_minimum(&mut self, min: usize) -> Result<(), DynModifyError> {
+        if self.vec.len() >= min {
+            self.min = min;
+            Ok(())
+        } else {
+            Err(DynModifyError { minimum: min })
+        }
+    }
+
+    /// Returns a slice to the minimum elements of the vector, which are guaranteed to exist.
+    #[inline]
+    pub const fn min_slice(&self) -> &[T] {
+        self.split_at_min().0
+    }
+
+    /// Returns a mutable slice to the minimum elements of the vector, which are guaranteed to exist.
+    #[inline]
+    pub const fn min_slice_mut(&mut self) -> &mut [T] {
+        self.split_at_min_mut().0
+    }
+
+    /// Returns a tuple of a slice to the minimum elements of the vector, which are guaranteed to exist, and a slice to the remaining elements of the vector.
+    #[inline]
+    pub const fn split_at_min(&self) -> (&[T], &[T]) {
+        self.debug_assert_invariant();
+
+        // Safety: The length of the vector is at least the minimum.
+        unsafe { self.vec.as_slice().split_at_unchecked(self.min) }
+    }
+
+    /// Returns a tuple of a mutable slice to the minimum elements of the vector, which are guaranteed to exist, and a mutable slice to the remaining elements of the vector.
+    #[inline]
+    pub const fn split_at_min_mut(&mut self) -> (&mut [T], &mut [T]) {
+        self.debug_assert_invariant();
+
+        let min = self.min;
+        // Safety: The length of the vector is at least the minimum.
+        unsafe { self.vec.as_mut_slice().split_at_mut_unchecked(min) }
+    }
+
+    /// Returns a slice to the elements after the minimum elements of the vector.
+    #[inline]
+    pub const fn extra_slice(&self) -> &[T] {
+        self.split_at_min().1
+    }
+
+    /// Returns a mutable slice to the elements after the minimum elements of the vector.
+    #[inline]
+    pub const fn extra_slice_mut(&mut self) -> &mut [T] {
+        self.split_at_min_mut().1
+    }
+}
+
+// --- Constructors, Convertors, and Destructors ---
+/// A vector that is too short to be a valid `DynVecMin` returned as an error in a constructor.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DynConstructError<T> {
+    /// The rejected vector.
+    pub vec: Vec<T>,
+    /// The minimum required length.
+    pub minimum: usize,
+}
+
+impl<T: Debug> Display for DynConstructError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Length {} of {:?} is less than the minimum {}",
+            self.vec.len(),
+            self.vec,
+            self.minimum
+        )
+    }
+}
+
+impl<T: Debug> Error for DynConstructError<T> {}
+
+impl<T> DynVecMin<T> {
+    /// Creates a new `DynVecMin` from a `Vec` with a minimum length of `min`.
+    ///
+    /// # Safety
+    /// - The length of the `Vec` must be at least `min`.
+    #[inline]
+    pub const unsafe fn from_vec_unchecked(vec: Vec<T>, min: usize) -> Self {
+        Self { vec, min }
+    }
+
+    /// Creates a new `DynVecMin` from a `Vec`, returning an error if the length of the provided `Vec` is less than `min`.
+    #[inline]
+    pub const fn try_from_vec(vec: Vec<T>, min: usize) -> Result<Self, DynConstructError<T>> {
+        if vec.len() >= min {
+            // Safety: We just checked that the length was at least `min`.
+            Ok(unsafe { Self::from_vec_unchecked(vec, min) })
+        } else {
+            Err(DynConstructError { vec, minimum: min })
+        }
+    }
+
+    /// Creates a new `DynVecMin` from anything that can be converted into a `Vec`, returning an error if the length of the provided `Vec` is less than `min`.
+    #[inline]
+    pub fn try_new(vec: impl Into<Vec<T>>, min: usize) -> Result<Self, DynConstructError<T>> {
+        Self::try_from_vec(vec.into(), min)
+    }
+
+    /// Creates a new `DynVecMin` from an iterator, returning an error if the length of the collected `Vec` is less than `min`.
+    #[inline]
+    pub fn collect(
+        iter: impl IntoIterator<Item = T>,
+        min: usize,
+    ) -> Result<Self, DynConstructError<T>> {
+        let iter = iter.into_iter();
+        let (low, _) = iter.size_hint();
+
+        let mut vec = Vec::with_capacity(low.max(min));
+        vec.extend(iter);
+        Self::try_from_vec(vec, min)
+    }
+
+    /// Creates a new `DynVecMin` from a `Vec`, padding it to `min` with elements generated by `f` if it is too short.
+    #[inline]
+    pub fn from_vec_padded_with(mut vec: Vec<T>, min: usize, f: impl FnMut() -> T) -> Self {
+        if vec.len() < min {
+            vec.resize_with(min, f);
+        }
+
+        // Safety: The vector was padded to at least `min` elements.
+        unsafe { Self::from_vec_unchecked(vec, min) }
+    }
+
+    /// Converts into a `VecMin` with a minimum of `M`, returning `self` as an error if the runtime minimum is less than `M`.
+    #[inline]
+    pub fn into_vec_min<const M: usize>(self) -> Result<VecMin<T, M>, Self> {
+        if self.min >= M {
+            // Safety: The length is at least the runtime minimum, which is at least `M`.
+            Ok(unsafe { VecMin::from_vec_unchecked(self.vec) })
+        } else {
+            Err(self)
+        }
+    }
+
+    /// See [`Vec::into_boxed_slice`].
+    #[inline]
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        self.vec.into_boxed_slice()
+    }
+
+    /// See [`Vec::leak`].
+    #[inline]
+    pub fn leak(self) -> &'static mut [T] {
+        self.vec.leak()
+    }
+
+    /// Returns the inner `Vec`, consuming the `DynVecMin`.
+    #[inline]
+    pub fn into_inner(self) -> Vec<T> {
+        self.vec
+    }
+
+    /// Returns a reference to the inner `Vec`.
+    #[inline]
+    pub fn vec(&self) -> &Vec<T> {
+        &self.vec
+    }
+}
+
+impl<T, const M: usize> From<VecMin<T, M>> for DynVecMin<T> {
+    #[inline]
+    fn from(vec_min: VecMin<T, M>) -> Self {
+        // Safety: A `VecMin<T, M>` has a length of at least `M`.
+        unsafe { Self::from_vec_unchecked(vec_min.into_inner(), M) }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for DynVecMin<T> {
+    /// Creates a `DynVecMin` with a minimum of `N`.
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        // Safety: The array has exactly `N` elements.
+        unsafe { Self::from_vec_unchecked(array.into(), N) }
+    }
+}
+
+impl<T> From<DynVecMin<T>> for Vec<T> {
+    #[inline]
+    fn from(dyn_vec_min: DynVecMin<T>) -> Self {
+        dyn_vec_min.vec
+    }
+}
+
+impl<T> From<DynVecMin<T>> for Box<[T]> {
+    #[inline]
+    fn from(dyn_vec_min: DynVecMin<T>) -> Self {
+        dyn_vec_min.vec.into_boxed_slice()
+    }
+}
+
+impl<T, const M: usize> TryFrom<DynVecMin<T>> for VecMin<T, M> {
+    type Error = DynVecMin<T>;
+
+    /// See [`DynVecMin::into_vec_min`].
+    #[inline]
+    fn try_from(dyn_vec_min: DynVecMin<T>) -> Result<Self, Self::Error> {
+        dyn_vec_min.into_vec_min()
+    }
+}
+
+impl<T, const N: usize> TryFrom<DynVecMin<T>> for [T; N] {
+    type Error = DynVecMin<T>;
+
+    #[inline]
+    fn try_from(dyn_vec_min: DynVecMin<T>) -> Result<[T; N], Self::Error> {
+        let min = dyn_vec_min.min;
+        // Safety: We obtained the original `Vec` from a valid `DynVecMin` with the same minimum.
+        dyn_vec_min
+            .vec
+            .try_into()
+            .map_err(|vec| unsafe { DynVecMin::from_vec_unchecked(vec, min) })
+    }
+}
+
+// --- View ---
+impl<T> DynVecMin<T> {
+    /// See [`Vec::as_slice`].
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] {
+        self.vec.as_slice()
+    }
+
+    /// See [`Vec::as_mut_slice`].
+    #[inline]
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        self.vec.as_mut_slice()
+    }
+
+    /// See [`Vec::as_ptr`].
+    #[inline]
+    pub const fn as_ptr(&self) -> *const T {
+        self.vec.as_ptr()
+    }
+
+    /// See [`Vec::as_mut_ptr`].
+    #[inline]
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.vec.as_mut_ptr()
+    }
+
+    /// See [`Vec::spare_capacity_mut`].
+    #[inline]
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self.vec.spare_capacity_mut()
+    }
+}
+
+impl<T> Deref for DynVecMin<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.vec.deref()
+    }
+}
+
+impl<T> DerefMut for DynVecMin<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.vec.deref_mut()
+    }
+}
+
+impl<T> AsRef<[T]> for DynVecMin<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.vec.as_ref()
+    }
+}
+
+impl<T> AsMut<[T]> for DynVecMin<T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        self.vec.as_mut()
+    }
+}
+
+impl<T> Borrow<[T]> for DynVecMin<T> {
+    #[inline]
+    fn borrow(&self) -> &[T] {
+        self.vec.borrow()
+    }
+}
+
+impl<T> BorrowMut<[T]> for DynVecMin<T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.vec.borrow_mut()
+    }
+}
+
+// --- Iterators ---
+impl<T> IntoIterator for DynVecMin<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+
+impl<'a, T: 'a> IntoIterator for &'a DynVecMin<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.iter()
+    }
+}
+
+impl<'a, T: 'a> IntoIterator for &'a mut DynVecMin<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.iter_mut()
+    }
+}
+
+// --- Immutable Access ---
+impl<T> DynVecMin<T> {
+    /// See [`Vec::capacity`].
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    /// See [`Vec::len`].
+    #[inline]
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(&self) -> usize {
+        self.vec.len()
+    }
+}
+
+// --- Mutable Access ---
+
+// -- Not Len Decreasing --
+
+// - Capacity -
+impl<T> DynVecMin<T> {
+    /// See [`Vec::reserve`].
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec.reserve(additional);
+    }
+
+    /// See [`Vec::reserve_exact`].
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.vec.reserve_exact(additional);
+    }
+
+    /// See [`Vec::try_reserve`].
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.vec.try_reserve(additional)
+    }
+
+    /// See [`Vec::try_reserve_exact`].
+    #[inline]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.vec.try_reserve_exact(additional)
+    }
+
+    /// See [`Vec::shrink_to_fit`].
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.vec.shrink_to_fit();
+    }
+
+    /// See [`Vec::shrink_to`].
+    #[inline]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.vec.shrink_to(min_capacity);
+    }
+}
+
+// - Growth -
+impl<T> DynVecMin<T> {
+    /// See [`Vec::push`].
+    #[inline]
+    pub fn push(&mut self, item: T) {
+        self.vec.push(item);
+    }
+
+    /// See [`Vec::insert`].
+    #[inline]
+    pub fn insert(&mut self, index: usize, element: T) {
+        self.vec.insert(index, element);
+    }
+
+    /// See [`Vec::append`].
+    #[inline]
+    pub fn append(&mut self, other: &mut Vec<T>) {
+        self.vec.append(other);
+    }
+
+    /// See [`Vec::extend_from_slice`].
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.vec.extend_from_slice(other);
+    }
+
+    /// See [`Vec::extend_from_within`].
+    #[inline]
+    pub fn extend_from_within<R>(&mut self, range: R)
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        self.vec.extend_from_within(range);
+    }
+}
+
+impl<T> Extend<T> for DynVecMin<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.vec.extend(iter);
+    }
+}
+
+impl<'a, T: Copy> Extend<&'a T> for DynVecMin<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.vec.extend(iter);
+    }
+}
+
+// -- Len Decreasing --
+impl<T> DynVecMin<T> {
+    #[inline]
+    fn error(&self) -> DynModifyError {
+        DynModifyError { minimum: self.min }
+    }
+
+    /// See [`Vec::pop`]. Pops an element from the vector if the length of the vector is greater than the minimum, otherwise does nothing and returns `None`.
+    #[inline]
+    pub fn pop_to_min(&mut self) -> Option<T> {
+        vec_min::pop_to_min(&mut self.vec, self.min)
+    }
+
+    /// See [`Vec::pop_if`]. Pops an element from the vector if the length of the vector is greater than the minimum and the provided predicate returns `true`, otherwise does nothing and returns `None`.
+    #[inline]
+    pub fn pop_to_min_if(&mut self, pred: impl FnOnce(&mut T) -> bool) -> Option<T> {
+        vec_min::pop_to_min_if(&mut self.vec, self.min, pred)
+    }
+
+    /// See [`Vec::remove`]. Returns an error if the operation would reduce the length of the vector below the minimum.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn remove(&mut self, index: usize) -> Result<T, DynModifyError> {
+        vec_min::remove(&mut self.vec, self.min, index).ok_or(self.error())
+    }
+
+    /// See [`Vec::swap_remove`]. Returns an error if the operation would reduce the length of the vector below the minimum.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn swap_remove(&mut self, index: usize) -> Result<T, DynModifyError> {
+        vec_min::swap_remove(&mut self.vec, self.min, index).ok_or(self.error())
+    }
+
+    /// See [`Vec::truncate`]. Returns an error if the operation would reduce the length of the vector below the minimum.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn truncate(&mut self, len: usize) -> Result<(), DynModifyError> {
+        vec_min::truncate(&mut self.vec, self.min, len).ok_or(self.error())
+    }
+
+    /// See [`Vec::truncate`]. Truncates the vector to `len` if `len` is greater than or equal to the minimum, otherwise truncates the vector to the minimum.
+    #[inline]
+    pub fn truncate_or_min(&mut self, len: usize) {
+        self.vec.truncate(len.max(self.min));
+    }
+
+    /// See [`Vec::truncate`]. Truncates the vector to the minimum.
+    #[inline]
+    pub fn truncate_to_min(&mut self) {
+        self.vec.truncate(self.min);
+    }
+
+    /// See [`Vec::clear`]. An alias of [`DynVecMin::truncate_to_min`], removing all elements after the minimum elements.
+    #[inline]
+    pub fn clear_to_min(&mut self) {
+        self.truncate_to_min();
+    }
+
+    /// See [`Vec::resize`]. Returns an error if the operation would reduce the length of the vector below the minimum.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn resize(&mut self, new_len: usize, value: T) -> Result<(), DynModifyError>
+    where
+        T: Clone,
+    {
+        vec_min::resize(&mut self.vec, self.min, new_len, value).ok_or(self.error())
+    }
+
+    /// See [`Vec::resize`]. Resizes the vector to `new_len` if `new_len` is greater than or equal to the minimum, otherwise resizes the vector to the minimum.
+    #[inline]
+    pub fn resize_or_min(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        self.vec.resize(new_len.max(self.min), value);
+    }
+
+    /// See [`Vec::resize_with`]. Returns an error if the operation would reduce the length of the vector below the minimum.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn resize_with<F>(&mut self, new_len: usize, generator: F) -> Result<(), DynModifyError>
+    where
+        F: FnMut() -> T,
+    {
+        vec_min::resize_with(&mut self.vec, self.min, new_len, generator).ok_or(self.error())
+    }
+
+    /// See [`Vec::resize_with`]. Resizes the vector to `new_len` if `new_len` is greater than or equal to the minimum, otherwise resizes the vector to the minimum.
+    #[inline]
+    pub fn resize_or_min_with<F>(&mut self, new_len: usize, generator: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.vec.resize_with(new_len.max(self.min), generator);
+    }
+
+    /// See [`Vec::drain`]. Returns an error if the operation would reduce the length of the vector below the minimum.
+    #[must_use = "this operation may fail"]
+    pub fn drain<R>(&mut self, range: R) -> Result<vec::Drain<'_, T>, DynModifyError>
+    where
+        R: RangeBounds<usize>,
+    {
+        let error = self.error();
+        vec_min::drain(&mut self.vec, self.min, range).ok_or(error)
+    }
+
+    /// See [`Vec::drain`]. Drains all elements after the minimum elements.
+    #[inline]
+    pub fn drain_extra(&mut self) -> vec::Drain<'_, T> {
+        self.vec.drain(self.min..)
+    }
+
+    /// See [`Vec::drain`]. Drains the `range` of the elements after the minimum elements, where index `0` is the first extra element.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds of the extra elements.
+    #[inline]
+    #[track_caller]
+    pub fn drain_extra_range<R>(&mut self, range: R) -> vec::Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        vec_min::drain_extra_range(&mut self.vec, self.min, range)
+    }
+
+    /// See [`Vec::split_off`]. Splits off all elements after the minimum elements.
+    #[inline]
+    pub fn split_off_extra(&mut self) -> Vec<T> {
+        self.vec.split_off(self.min)
+    }
+
+    /// Removes all elements after the minimum elements, returning them and shrinking the capacity of the vector as much as possible.
+    #[inline]
+    pub fn take_extra(&mut self) -> Vec<T> {
+        vec_min::take_extra(&mut self.vec, self.min)
+    }
+
+    /// See [`Vec::split_off`]. Returns an error if the operation would reduce the length of the vector below the minimum.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn split_off(&mut self, at: usize) -> Result<Vec<T>, DynModifyError> {
+        vec_min::split_off(&mut self.vec, self.min, at).ok_or(self.error())
+    }
+
+    /// See [`Vec::extract_if`]. Stops extracting once the length of the vector would drop below the minimum, leaving the remaining elements in place.
+    ///
+    /// See [`VecMin::extract_if`] for the cost of extraction.
+    pub fn extract_if<F, R>(&mut self, range: R, filter: F) -> ExtractIf<'_, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+        R: RangeBounds<usize>,
+    {
+        ExtractIf {
+            raw: RawExtractIf::new(&mut self.vec, self.min, range, filter),
+        }
+    }
+
+    /// See [`Vec::splice`]. Returns an error if the replacement would reduce the length of the vector below the minimum.
+    ///
+    /// Like [`VecMin::splice`] the replacement is collected first and the removed elements are returned in a `Vec`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Result<Vec<T>, DynModifyError>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        vec_min::splice(&mut self.vec, self.min, range, replace_with).ok_or(self.error())
+    }
+}
+
+/// An iterator which uses a closure to determine if an element should be removed from a [`DynVecMin`].
+///
+/// Created by [`DynVecMin::extract_if`]. Extraction stops once the vector reaches its minimum length.
+pub struct ExtractIf<'a, T, F> {
+    raw: RawExtractIf<'a, T, F>,
+}
+
+impl<T, F> ExtractIf<'_, T, F> {
+    /// Returns `true` if extraction stopped because the vector reached its minimum length before the whole range was visited.
+    #[inline]
+    pub fn is_stopped(&self) -> bool {
+        self.raw.is_stopped()
+    }
+}
+
+impl<T, F> Iterator for ExtractIf<'_, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.raw.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.raw.size_hint()
+    }
+}
+
+impl<T: Debug, F> Debug for ExtractIf<'_, T, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ExtractIf")
+            .field(&self.raw.remaining())
+            .finish()
+    }
+}
+
+// --- Scoped Access ---
+impl<T> DynVecMin<T> {
+    /// Returns a guard with full access to a copy of the inner `Vec`.
+    ///
+    /// The copy replaces the contents of the vector on [`DynVecMinGuard::commit`] if it satisfies the minimum length,
+    /// otherwise the vector is left unchanged.
+    #[inline]
+    pub fn guard(&mut self) -> DynVecMinGuard<'_, T>
+    where
+        T: Clone,
+    {
+        let vec = self.vec.clone();
+        DynVecMinGuard {
+            dyn_vec_min: self,
+            vec,
+        }
+    }
+
+    /// Calls `f` with full access to the inner `Vec`, returning an error and restoring the previous contents if the
+    /// length was reduced below the minimum.
+    ///
+    /// Like [`VecMin::with_vec`] `f` is given a clone of the vector, see [`DynVecMin::with_vec_or_repair`] to mutate in
+    /// place.
+    #[inline]
+    pub fn with_vec<R>(&mut self, f: impl FnOnce(&mut Vec<T>) -> R) -> Result<R, DynModifyError>
+    where
+        T: Clone,
+    {
+        let mut guard = self.guard();
+        let result = f(&mut guard);
+        guard.commit().map(|()| result)
+    }
+
+    /// Calls `f` with full access to the inner `Vec`, returning an error and filling the vector to the minimum with
+    /// `repair` if the length was reduced below the minimum.
+    ///
+    /// See [`VecMin::with_vec_or_repair`] for the behavior when `f` or `repair` panic.
+    pub fn with_vec_or_repair<R>(
+        &mut self,
+        f: impl FnOnce(&mut Vec<T>) -> R,
+        repair: impl FnMut() -> T,
+    ) -> Result<R, DynModifyError> {
+        vec_min::with_vec_or_repair(&mut self.vec, self.min, f, repair).ok_or(self.error())
+    }
+}
+
+/// A guard with full access to a copy of the inner `Vec` of a [`DynVecMin`].
+///
+/// Created by [`DynVecMin::guard`]. Changes are only applied to the `DynVecMin` by [`DynVecMinGuard::commit`].
+#[derive(Debug)]
+pub struct DynVecMinGuard<'a, T> {
+    dyn_vec_min: &'a mut DynVecMin<T>,
+    vec: Vec<T>,
+}
+
+impl<T> DynVecMinGuard<'_, T> {
+    /// Replaces the contents of the `DynVecMin` with the guarded `Vec`, returning an error and discarding the changes if
+    /// the length is less than the minimum.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn commit(self) -> Result<(), DynModifyError> {
+        let error = self.dyn_vec_min.error();
+        vec_min::commit(&mut self.dyn_vec_min.vec, self.dyn_vec_min.min, self.vec).ok_or(error)
+    }
+}
+
+impl<T> Deref for DynVecMinGuard<'_, T> {
+    type Target = Vec<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+impl<T> DerefMut for DynVecMinGuard<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.vec
+    }
+}
+
+// --- Equality & Ordering ---
+impl<T: PartialEq> PartialEq for DynVecMin<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq> Eq for DynVecMin<T> {}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for DynVecMin<T> {
+    #[inline]
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq> PartialEq<DynVecMin<T>> for Vec<T> {
+    #[inline]
+    fn eq(&self, other: &DynVecMin<T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, const M: usize> PartialEq<VecMin<T, M>> for DynVecMin<T> {
+    #[inline]
+    fn eq(&self, other: &VecMin<T, M>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, const M: usize> PartialEq<DynVecMin<T>> for VecMin<T, M> {
+    #[inline]
+    fn eq(&self, other: &DynVecMin<T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for DynVecMin<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord> Ord for DynVecMin<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: Hash> Hash for DynVecMin<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.vec.hash(state);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod __serde {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    /// Serialized as a `(minimum, elements)` tuple, since the minimum is not part of the type.
+    impl<T: Serialize> Serialize for DynVecMin<T> {
+        #[inline]
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            (self.min, &self.vec).serialize(serializer)
+        }
+    }
+
+    impl<'de, T: Deserialize<'de> + Debug> Deserialize<'de> for DynVecMin<T> {
+        #[inline]
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let (min, vec) = <(usize, Vec<T>)>::deserialize(deserializer)?;
+            Self::try_from_vec(vec, min).map_err(Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::{DynConstructError, DynVecMin};
+    use crate::{DynModifyError, ModifyError, VecMin, vecmin};
+
+    const ERR: DynModifyError = DynModifyError { minimum: 2 };
+
+    #[test]
+    fn dyn_vec_min() {
+        assert_eq!(
+            DynVecMin::try_from_vec(vec![1], 2).unwrap_err(),
+            DynConstructError {
+                vec: vec![1],
+                minimum: 2
+            }
+        );
+        assert!(DynVecMin::collect(0..1, 2).is_err());
+
+        let mut v = DynVecMin::try_new([1, 2, 3], 2).unwrap();
+        assert_eq!(v.split_at_min(), (&[1, 2][..], &[3][..]));
+        assert_eq!(v.pop_to_min(), Some(3));
+        assert_eq!(v.pop_to_min(), None);
+        assert_eq!(v.remove(0), Err(ERR));
+
+        assert_eq!(v.set_minimum(3), Err(DynModifyError { minimum: 3 }));
+        assert_eq!(v.set_minimum(1), Ok(()));
+        assert_eq!(v.remove(0), Ok(1));
+        assert_eq!(v.minimum(), 1);
+        assert!(v.is_minimum());
+
+        let v = DynVecMin::from_vec_padded_with(vec![2, 4], 3, || 0);
+        assert_eq!(vec![2, 4, 0], v);
+        assert_eq!(vecmin![2, 4, 0], v);
+        assert!(v.clone().into_vec_min::<4>().is_err());
+        assert_eq!(v.into_vec_min::<3>().unwrap(), vecmin![2, 4, 0]);
+
+        let v = DynVecMin::from(VecMin::<_, 1>::try_new([1, 2]).unwrap());
+        assert_eq!(v.minimum(), 1);
+        assert_eq!(
+            DynModifyError::from(ModifyError::<3>),
+            DynModifyError { minimum: 3 }
+        );
+    }
+
+    #[test]
+    fn len_decreasing() {
+        let mut v = DynVecMin::from([1, 2, 3, 4, 5]);
+        assert_eq!(v.set_minimum(2), Ok(()));
+
+        assert!(v.drain(1..).is_err());
+        assert_eq!(v.drain(1..4).unwrap().collect::<Vec<_>>(), [2, 3, 4]);
+        assert_eq!(v, vec![1, 5]);
+
+        v.extend([6, 7, 8]);
+        assert_eq!(v.drain_extra_range(1..).collect::<Vec<_>>(), [7, 8]);
+        assert_eq!(v.drain_extra().collect::<Vec<_>>(), [6]);
+
+        v.extend([6, 7]);
+        assert_eq!(v.split_off(1), Err(ERR));
+        assert_eq!(v.split_off(3), Ok(vec![7]));
+        assert_eq!(v.take_extra(), [6]);
+        assert_eq!(v.capacity(), 2);
+
+        assert_eq!(v.truncate(1), Err(ERR));
+        v.truncate_or_min(0);
+        assert_eq!(v, vec![1, 5]);
+
+        assert_eq!(v.resize(1, 0), Err(ERR));
+        assert_eq!(v.resize_with(3, || 9), Ok(()));
+        v.resize_or_min(0, 0);
+        assert_eq!(v, vec![1, 5]);
+        v.resize_or_min_with(4, Default::default);
+        v.clear_to_min();
+        assert_eq!(v, vec![1, 5]);
+    }
+
+    #[test]
+    fn extract_if_and_splice() {
+        let mut v = DynVecMin::try_new([1, 2, 3, 4, 5, 6], 2).unwrap();
+        let mut iter = v.extract_if(.., |x| *x > 1);
+        assert_eq!(iter.by_ref().collect::<Vec<_>>(), [2, 3, 4, 5]);
+        assert!(iter.is_stopped());
+        drop(iter);
+        assert_eq!(v, vec![1, 6]);
+
+        assert_eq!(v.splice(.., [7]), Err(ERR));
+        assert_eq!(v.splice(1.., [7, 8]), Ok(vec![6]));
+        assert_eq!(v, vec![1, 7, 8]);
+    }
+
+    #[test]
+    fn with_vec() {
+        let mut v = DynVecMin::try_new([1, 2, 3], 2).unwrap();
+        assert_eq!(v.with_vec(|vec| vec.clear()), Err(ERR));
+        assert_eq!(v.with_vec(|vec| vec.pop()), Ok(Some(3)));
+
+        let mut guard = v.guard();
+        guard.push(3);
+        assert_eq!(guard.commit(), Ok(()));
+        assert_eq!(v, vec![1, 2, 3]);
+
+        assert_eq!(v.with_vec_or_repair(|vec| vec.clear(), || 0), Err(ERR));
+        assert_eq!(v, vec![0, 0]);
+    }
+
+    #[test]
+    fn conversions() {
+        let v = DynVecMin::try_new([1, 2, 3], 2).unwrap();
+        assert_eq!(<[_; 2]>::try_from(v.clone()), Err(v.clone()));
+        assert_eq!(<[_; 3]>::try_from(v.clone()), Ok([1, 2, 3]));
+        assert!(VecMin::<_, 3>::try_from(v.clone()).is_err());
+        assert_eq!(
+            VecMin::<_, 1>::try_from(v.clone()).unwrap(),
+            vecmin![1, 2, 3]
+        );
+        assert_eq!(Box::<[_]>::from(v), Box::from([1, 2, 3]));
+    }
+}
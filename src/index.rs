@@ -0,0 +1,136 @@
+//! Implementation of the [`MinIndex`] bounded index.
+
+use core::fmt::{self, Display, Formatter};
+use core::ops::{Index, IndexMut};
+use core::slice::SliceIndex;
+
+use crate::{Assert, VecMin};
+
+/// An index that is guaranteed to be less than `M`.
+///
+/// Indexing a [`VecMin`] or an array of length `M` with a `MinIndex<M>` never needs a bounds check.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MinIndex<const M: usize>(usize);
+
+impl<const M: usize> MinIndex<M> {
+    /// Creates a new `MinIndex`, returning `None` if `index` is not less than `M`.
+    #[inline]
+    pub const fn new(index: usize) -> Option<Self> {
+        if index < M { Some(Self(index)) } else { None }
+    }
+
+    /// Creates a new `MinIndex` from a constant `I`, which is checked at compile time to be less than `M`.
+    #[inline]
+    pub const fn from_const<const I: usize>() -> Self {
+        let () = Assert::<I, M>::INDEX;
+        Self(I)
+    }
+
+    /// Creates a new `MinIndex` without checking the bound.
+    ///
+    /// # Safety
+    /// - `index` must be less than `M`.
+    #[inline]
+    pub const unsafe fn new_unchecked(index: usize) -> Self {
+        debug_assert!(index < M);
+        Self(index)
+    }
+
+    /// Returns the index as a `usize`.
+    #[inline]
+    pub const fn get(self) -> usize {
+        self.0
+    }
+
+    /// Returns an iterator over all indices from `0` to `M` in ascending order.
+    #[inline]
+    pub fn all() -> impl ExactSizeIterator<Item = Self> + DoubleEndedIterator {
+        (0..M).map(Self)
+    }
+}
+
+impl<const M: usize> From<MinIndex<M>> for usize {
+    #[inline]
+    fn from(index: MinIndex<M>) -> Self {
+        index.0
+    }
+}
+
+impl<const M: usize> Display for MinIndex<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+// `VecMin` implements `Index` for `MinIndex`, so slice indexing is no longer reached through `Deref`.
+impl<T, I: SliceIndex<[T]>, const M: usize> Index<I> for VecMin<T, M> {
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        self.as_slice().index(index)
+    }
+}
+
+impl<T, I: SliceIndex<[T]>, const M: usize> IndexMut<I> for VecMin<T, M> {
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        self.as_mut_slice().index_mut(index)
+    }
+}
+
+impl<T, const M: usize> Index<MinIndex<M>> for VecMin<T, M> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: MinIndex<M>) -> &T {
+        self.min_slice().index(index)
+    }
+}
+
+impl<T, const M: usize> IndexMut<MinIndex<M>> for VecMin<T, M> {
+    #[inline]
+    fn index_mut(&mut self, index: MinIndex<M>) -> &mut T {
+        self.min_slice_mut().index_mut(index)
+    }
+}
+
+impl<T, const M: usize> Index<MinIndex<M>> for [T; M] {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: MinIndex<M>) -> &T {
+        // Safety: A `MinIndex<M>` is always less than `M`.
+        unsafe { self.get_unchecked(index.0) }
+    }
+}
+
+impl<T, const M: usize> IndexMut<MinIndex<M>> for [T; M] {
+    #[inline]
+    fn index_mut(&mut self, index: MinIndex<M>) -> &mut T {
+        // Safety: A `MinIndex<M>` is always less than `M`.
+        unsafe { self.get_unchecked_mut(index.0) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MinIndex;
+    use crate::vecmin;
+
+    #[test]
+    fn min_index() {
+        let mut v = vecmin![3; [1, 2, 3, 4]];
+
+        assert_eq!(MinIndex::<3>::new(3), None);
+        assert_eq!(v[MinIndex::<3>::from_const::<1>()], 2);
+
+        for i in MinIndex::<3>::all() {
+            v[i] *= 10;
+        }
+        assert_eq!(v, [10, 20, 30, 4].to_vec());
+        assert_eq!(v[3], 4);
+        assert_eq!(v[1..], [20, 30, 4]);
+        assert_eq!(v.min_slice()[MinIndex::new(2).unwrap()], 30);
+    }
+}
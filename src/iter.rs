@@ -0,0 +1,260 @@
+//! Fallible collection of iterators into minimum length types.
+
+use alloc::collections::BTreeMap;
+use alloc::collections::btree_map;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, hash_map};
+
+use crate::vec::ConstructError;
+use crate::{VecMin, VecOne};
+
+/// Conversion from an iterator that fails if the collected elements violate an invariant of `Self`.
+///
+/// Implemented by [`VecMin`], [`SortedVecMin`](crate::SortedVecMin), [`ValidatedVec`](crate::ValidatedVec) and
+/// [`ZipperOne`](crate::ZipperOne). [`DynVecMin`](crate::DynVecMin) is not, since its minimum is not part of its type.
+pub trait TryFromIterator<A>: Sized {
+    /// The error returned when the collected elements are rejected.
+    type Error;
+
+    /// Creates a value from an iterator, returning an error if the collected elements are rejected.
+    fn try_from_iter<I: IntoIterator<Item = A>>(iter: I) -> Result<Self, Self::Error>;
+}
+
+impl<T, const M: usize> TryFromIterator<T> for VecMin<T, M> {
+    type Error = ConstructError<T, M>;
+
+    #[inline]
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Self::Error> {
+        Self::collect(iter)
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl<T, E> Sealed for Result<T, E> {}
+    impl<T> Sealed for Option<T> {}
+}
+
+/// An item that can short-circuit [`IterMinExt::try_collect_min`], implemented for `Result` and `Option`.
+pub trait TryItem: private::Sealed {
+    /// The type of a successful item.
+    type Output;
+    /// The type of a failed item, `E` for `Result<T, E>` and `()` for `Option<T>`.
+    type Residual;
+
+    /// Splits the item into its successful or failed value.
+    fn branch(self) -> Result<Self::Output, Self::Residual>;
+}
+
+impl<T, E> TryItem for Result<T, E> {
+    type Output = T;
+    type Residual = E;
+
+    #[inline]
+    fn branch(self) -> Result<T, E> {
+        self
+    }
+}
+
+impl<T> TryItem for Option<T> {
+    type Output = T;
+    type Residual = ();
+
+    #[inline]
+    fn branch(self) -> Result<T, ()> {
+        self.ok_or(())
+    }
+}
+
+/// Extension methods for collecting iterators into a [`VecMin`].
+pub trait IterMinExt: Iterator + Sized {
+    /// Collects the iterator into a `VecMin`, see [`VecMin::collect`].
+    #[inline]
+    fn collect_min<const M: usize>(
+        self,
+    ) -> Result<VecMin<Self::Item, M>, ConstructError<Self::Item, M>> {
+        VecMin::collect(self)
+    }
+
+    /// Collects the iterator into a `VecMin`, preallocating at most `cap` elements from the size hint, but at least `M`.
+    #[inline]
+    fn collect_min_bounded<const M: usize>(
+        self,
+        cap: usize,
+    ) -> Result<VecMin<Self::Item, M>, ConstructError<Self::Item, M>> {
+        let (low, high) = self.size_hint();
+        let capacity = high.unwrap_or(low).min(cap).max(M);
+
+        VecMin::collect_with_capacity(self, capacity)
+    }
+
+    /// Collects an iterator of `Result` or `Option` items into a `VecMin`, stopping at the first failed item.
+    ///
+    /// The outer result holds the failed item, the inner result holds the length error.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn try_collect_min<const M: usize>(
+        self,
+    ) -> Result<
+        Result<
+            VecMin<<Self::Item as TryItem>::Output, M>,
+            ConstructError<<Self::Item as TryItem>::Output, M>,
+        >,
+        <Self::Item as TryItem>::Residual,
+    >
+    where
+        Self::Item: TryItem,
+    {
+        let mut residual = None;
+        let collected = VecMin::collect(self.map_while(|item| match item.branch() {
+            Ok(output) => Some(output),
+            Err(error) => {
+                residual = Some(error);
+                None
+            }
+        }));
+
+        match residual {
+            Some(error) => Err(error),
+            None => Ok(collected),
+        }
+    }
+
+    /// Groups the values of key-value pairs by their key into a `HashMap` of non-empty vectors.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn into_group_map_one<K, V>(self) -> HashMap<K, VecOne<V>>
+    where
+        Self: Iterator<Item = (K, V)>,
+        K: Hash + Eq,
+    {
+        let mut map = HashMap::<K, VecOne<V>>::new();
+        for (key, value) in self {
+            match map.entry(key) {
+                hash_map::Entry::Occupied(mut entry) => entry.get_mut().push(value),
+                hash_map::Entry::Vacant(entry) => {
+                    entry.insert(VecOne::new(value));
+                }
+            }
+        }
+        map
+    }
+
+    /// Groups the values of key-value pairs by their key into a `BTreeMap` of non-empty vectors.
+    #[inline]
+    fn into_group_btree_map_one<K, V>(self) -> BTreeMap<K, VecOne<V>>
+    where
+        Self: Iterator<Item = (K, V)>,
+        K: Ord,
+    {
+        let mut map = BTreeMap::<K, VecOne<V>>::new();
+        for (key, value) in self {
+            match map.entry(key) {
+                btree_map::Entry::Occupied(mut entry) => entry.get_mut().push(value),
+                btree_map::Entry::Vacant(entry) => {
+                    entry.insert(VecOne::new(value));
+                }
+            }
+        }
+        map
+    }
+
+    /// Groups the items by the key returned from `f` into a `HashMap` of non-empty vectors.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn group_by_key<K>(self, mut f: impl FnMut(&Self::Item) -> K) -> HashMap<K, VecOne<Self::Item>>
+    where
+        K: Hash + Eq,
+    {
+        self.map(|item| (f(&item), item)).into_group_map_one()
+    }
+
+    /// Groups the items by the key returned from `f` into a `BTreeMap` of non-empty vectors.
+    #[inline]
+    fn group_by_key_btree<K>(
+        self,
+        mut f: impl FnMut(&Self::Item) -> K,
+    ) -> BTreeMap<K, VecOne<Self::Item>>
+    where
+        K: Ord,
+    {
+        self.map(|item| (f(&item), item)).into_group_btree_map_one()
+    }
+
+    /// Flattens groups of values back into key-value pairs, reversing [`IterMinExt::into_group_btree_map_one`].
+    #[inline]
+    fn ungroup<K, V, const M: usize>(self) -> impl Iterator<Item = (K, V)>
+    where
+        Self: Iterator<Item = (K, VecMin<V, M>)>,
+        K: Clone,
+    {
+        self.flat_map(|(key, values)| values.into_iter().map(move |value| (key.clone(), value)))
+    }
+}
+
+impl<I: Iterator> IterMinExt for I {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::{IterMinExt, TryFromIterator};
+    use crate::{VecMin, vecmin, vecone};
+
+    #[test]
+    fn collect_min() {
+        assert!((0..2).collect_min::<3>().is_err());
+        assert_eq!((0..3).collect_min::<3>().unwrap(), vec![0, 1, 2]);
+
+        let v = (0..100)
+            .filter(|x| *x < 2)
+            .collect_min_bounded::<2>(4)
+            .unwrap();
+        assert_eq!(v.capacity(), 4);
+
+        assert_eq!(VecMin::<_, 1>::try_from_iter([1]).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn try_collect_min() {
+        let items = [Ok(1), Err("bad"), Ok(3)];
+        assert_eq!(items.into_iter().try_collect_min::<1>(), Err("bad"));
+
+        let items: [Result<_, ()>; 2] = [Ok(1), Ok(2)];
+        assert!(items.into_iter().try_collect_min::<3>().unwrap().is_err());
+
+        let items = [Some(1), Some(2)];
+        assert_eq!(
+            items.into_iter().try_collect_min::<2>(),
+            Ok(Ok(vecmin![1, 2]))
+        );
+        assert_eq!([Some(1), None].into_iter().try_collect_min::<1>(), Err(()));
+    }
+
+    #[test]
+    fn group_map() {
+        let map = [(1, 'a'), (2, 'b'), (1, 'c')]
+            .into_iter()
+            .into_group_btree_map_one();
+        assert_eq!(map[&1], vecone!['a', 'c']);
+        assert_eq!(map[&2], vecone!['b']);
+
+        let pairs: Vec<_> = map.into_iter().ungroup().collect();
+        assert_eq!(pairs, [(1, 'a'), (1, 'c'), (2, 'b')]);
+
+        let map = (0..5).group_by_key_btree(|x| x % 2);
+        assert_eq!(map[&0], vecone![0, 2, 4]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn group_hash_map() {
+        let map = ["ab", "cd", "a"].into_iter().group_by_key(|s| s.len());
+        assert_eq!(map[&2], vecone!["ab", "cd"]);
+        assert_eq!(map[&1], vecone!["a"]);
+    }
+}
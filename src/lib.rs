@@ -1,10 +1,34 @@
 //! Provides a `VecMin` and `VecOne` newtype wrapper around `Vec` that enforces a minimum length at compile time.
+//!
+//! # Features
+//! - `serde`: Implements `Serialize` and `Deserialize`, rejecting vectors shorter than their minimum.
+//! - `std`: Adds the `HashMap` backed `MultiMapOne` in [`multimap`] and the `HashMap` grouping methods of
+//!   [`IterMinExt`], and enables `std` support in `serde`. Without it the crate only needs `alloc`.
+//! - `nightly`: Adds `concat`, `VecMin::push_grow`, `VecMin::pop_shrink` and `VecMin::split_at_const`, which track
+//!   the minimum through their return types. Requires a nightly compiler and enables the incomplete
+//!   `generic_const_exprs` language feature for the whole crate, so it may break with any nightly release and is not
+//!   covered by semver.
 
 #![no_std]
-
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+
+pub mod builder;
+pub mod cursor;
+pub mod dyn_vec;
+pub mod index;
+pub mod iter;
+pub mod multimap;
+#[cfg(feature = "nightly")]
+mod nightly;
+pub mod sorted;
+pub mod validated;
 pub mod vec;
+pub mod zipper;
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[doc(hidden)]
 pub extern crate alloc as __alloc;
@@ -13,7 +37,20 @@ use core::error::Error;
 use core::fmt::{self, Debug, Display, Formatter};
 use core::ops::{Bound, Range, RangeBounds, RangeTo};
 
+pub use builder::VecMinBuilder;
+pub use cursor::CursorMut;
+pub use dyn_vec::DynVecMin;
+pub use index::MinIndex;
+pub use iter::{IterMinExt, TryFromIterator};
+pub use multimap::BTreeMultiMapOne;
+#[cfg(feature = "std")]
+pub use multimap::MultiMapOne;
+#[cfg(feature = "nightly")]
+pub use nightly::concat;
+pub use sorted::SortedVecMin;
+pub use validated::{Invariant, ValidatedVec};
 pub use vec::{VecMin, VecOne};
+pub use zipper::{Overflow, ZipperOne};
 
 /// An error indicating that an operation would reduce the length of a vector below its minimum required length.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -31,6 +68,104 @@ impl<const M: usize> Display for ModifyError<M> {
 
 impl<const M: usize> Error for ModifyError<M> {}
 
+/// An error indicating that an operation would reduce the length of a [`DynVecMin`] below its runtime minimum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DynModifyError {
+    /// The minimum required length.
+    pub minimum: usize,
+}
+
+impl Display for DynModifyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "operation would reduce length below minimum required {}",
+            self.minimum
+        )
+    }
+}
+
+impl Error for DynModifyError {}
+
+impl<const M: usize> From<ModifyError<M>> for DynModifyError {
+    #[inline]
+    fn from(_: ModifyError<M>) -> Self {
+        Self { minimum: M }
+    }
+}
+
+/// An error indicating that splitting a vector would leave one of the halves below its minimum required length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SplitError<const M: usize, const K: usize> {
+    /// The vector being split would be shorter than `M`.
+    Head(ModifyError<M>),
+    /// The vector split off would be shorter than `K`.
+    Tail(ModifyError<K>),
+}
+
+impl<const M: usize, const K: usize> Display for SplitError<M, K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Head(error) => Display::fmt(error, f),
+            Self::Tail(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<const M: usize, const K: usize> Error for SplitError<M, K> {}
+
+/// Compile time assertions between const generic parameters, evaluated when a `let () = Assert::<I, M>::INDEX;` is
+/// monomorphized.
+///
+/// Each check has its own constant so that a failure names the bound that was violated. Inline `const` blocks would
+/// give the same messages at the call site, but are rejected as overly complex generic constants with the `nightly`
+/// feature.
+struct Assert<const A: usize, const B: usize>;
+
+impl<const A: usize, const B: usize> Assert<A, B> {
+    /// Asserts that the index `A` is less than the minimum `B`.
+    const INDEX: () = assert!(A < B, "index `I` must be less than `M`");
+
+    /// Asserts that the chunk length `A` is at most the minimum `B`.
+    const CHUNK: () = assert!(A <= B, "chunk length `K` must be at most `M` (`K <= M`)");
+
+    /// Asserts that the chunk length `A` is in `1..=B`.
+    const ARRAY_CHUNK: () = assert!(A > 0 && A <= B, "chunk length `K` must be in `1..=M`");
+
+    /// Asserts that the window length `A` is in `1..=B`.
+    const WINDOW: () = assert!(A > 0 && A <= B, "window length `K` must be in `1..=M`");
+
+    /// Asserts that the minimum `A` is at most the array length `B`.
+    const ARRAY_LEN: () = assert!(A <= B, "array length `N` must be at least `M`");
+}
+
+/// Compile time assertion on the minimum `M`, see [`Assert`].
+struct AssertMin<const M: usize>;
+
+impl<const M: usize> AssertMin<M> {
+    /// Asserts that `M > 0`.
+    const NON_EMPTY: () = assert!(M > 0, "minimum length `M` must be at least 1");
+}
+
+/// Compile time assertion that `A + B <= C`, see [`Assert`].
+struct AssertSum<const A: usize, const B: usize, const C: usize>;
+
+impl<const A: usize, const B: usize, const C: usize> AssertSum<A, B, C> {
+    /// Asserts that the lengths `A` and `B` of the halves sum to at most the minimum `C`.
+    const SPLIT: () = assert!(A + B <= C, "split minimums `A + B` must be at most `M`");
+}
+
+/// Compile time assertion that `A * B >= C`, see [`Assert`].
+struct AssertProduct<const A: usize, const B: usize, const C: usize>;
+
+impl<const A: usize, const B: usize, const C: usize> AssertProduct<A, B, C> {
+    /// Asserts that the flattened minimum `C` is at most the product of the outer and inner minimums `A * B`.
+    const FLATTEN: () = assert!(
+        A * B >= C,
+        "flattened minimum `N` must be at most the product of the minimums"
+    );
+}
+
 #[inline]
 #[track_caller]
 fn slice_range<R>(range: &R, bounds: RangeTo<usize>) -> Range<usize>
@@ -115,12 +250,14 @@ macro_rules! vecone {
 macro_rules! vecmin {
     ($min:expr; [$x:expr; $n:expr]) => {{
         let _: [(); $n - $min];
-        unsafe { $crate::VecMin::<_, $min>::from_vec_unchecked($crate::__alloc::vec![$x; $n]) }
+        let vec = $crate::__alloc::vec![$x; $n];
+        unsafe { $crate::VecMin::<_, $min>::from_vec_unchecked(vec) }
     }};
     ($min:expr; [$($x:expr),+ $(,)?]) => {{
-        const N: usize = <[()]>::len(&[$( { let _ = &$x; () }),+]);
+        const N: usize = <[()]>::len(&[$( { let _ = &$x; }),+]);
         let _: [(); N - $min];
-        unsafe { $crate::VecMin::<_, $min>::from_vec_unchecked($crate::__alloc::vec![$($x),+]) }
+        let vec = $crate::__alloc::vec![$($x),+];
+        unsafe { $crate::VecMin::<_, $min>::from_vec_unchecked(vec) }
     }};
     ($x:expr; $n:expr) => {
         $crate::VecMin::from_array([$x; $n])
@@ -195,4 +332,15 @@ mod tests {
     //     let v = vecone![2; n];
     //     let v = vecmin![2; [1; n]];
     // }
+
+    // fn const_bounds() {
+    //     let v = vecmin![1, 2];
+    //     let w = v.array_windows::<3>();
+    //     let c = v.array_chunks::<0>();
+    //     let p = vecone![1].pairwise();
+    //     let a = v.at::<2>();
+    //     let f = v.first_chunk::<3>();
+    //     let n = VecMin::<_, 3>::from_array_n([1, 2]);
+    //     let f: VecMin<_, 3> = VecMin::from_array([vecone![1], vecone![2]]).flatten();
+    // }
 }
@@ -0,0 +1,303 @@
+//! Implementation of the `MultiMapOne` and [`BTreeMultiMapOne`] multimaps.
+
+use core::borrow::Borrow;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+
+use alloc::collections::{BTreeMap, btree_map};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, hash_map};
+
+use crate::VecOne;
+
+macro_rules! multimap {
+    ($(#[$attr:meta])* $name:ident, $map:ident, $entry:ident, $($bound:tt)+) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug)]
+        pub struct $name<K, V> {
+            map: $map<K, VecOne<V>>,
+        }
+
+        impl<K, V> $name<K, V> {
+            /// Creates an empty multimap.
+            #[inline]
+            pub fn new() -> Self {
+                Self { map: $map::new() }
+            }
+
+            /// Returns the number of keys in the multimap.
+            #[inline]
+            pub fn len(&self) -> usize {
+                self.map.len()
+            }
+
+            /// Returns `true` if the multimap contains no keys.
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.map.is_empty()
+            }
+
+            /// Removes all keys and values.
+            #[inline]
+            pub fn clear(&mut self) {
+                self.map.clear();
+            }
+
+            /// Returns an iterator over the keys and their values.
+            #[inline]
+            pub fn iter(&self) -> $entry::Iter<'_, K, VecOne<V>> {
+                self.map.iter()
+            }
+
+            /// Returns an iterator over the keys.
+            #[inline]
+            pub fn keys(&self) -> $entry::Keys<'_, K, VecOne<V>> {
+                self.map.keys()
+            }
+
+            /// Returns a reference to the underlying map.
+            #[inline]
+            pub fn as_map(&self) -> &$map<K, VecOne<V>> {
+                &self.map
+            }
+
+            /// Returns the underlying map, consuming the multimap.
+            #[inline]
+            pub fn into_map(self) -> $map<K, VecOne<V>> {
+                self.map
+            }
+        }
+
+        impl<K: $($bound)+, V> $name<K, V> {
+            /// Appends `value` to the values of `key`, creating the key if it does not exist.
+            #[inline]
+            pub fn insert(&mut self, key: K, value: V) {
+                match self.map.entry(key) {
+                    $entry::Entry::Occupied(mut entry) => entry.get_mut().push(value),
+                    $entry::Entry::Vacant(entry) => {
+                        entry.insert(VecOne::new(value));
+                    }
+                }
+            }
+
+            /// Returns the values of `key`, which are guaranteed to be non-empty.
+            #[inline]
+            pub fn get<Q>(&self, key: &Q) -> Option<&VecOne<V>>
+            where
+                K: Borrow<Q>,
+                Q: $($bound)+ + ?Sized,
+            {
+                self.map.get(key)
+            }
+
+            /// Returns the values of `key` mutably, which are guaranteed to be non-empty.
+            #[inline]
+            pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut VecOne<V>>
+            where
+                K: Borrow<Q>,
+                Q: $($bound)+ + ?Sized,
+            {
+                self.map.get_mut(key)
+            }
+
+            /// Returns `true` if the multimap contains `key`.
+            #[inline]
+            pub fn contains_key<Q>(&self, key: &Q) -> bool
+            where
+                K: Borrow<Q>,
+                Q: $($bound)+ + ?Sized,
+            {
+                self.map.contains_key(key)
+            }
+
+            /// Removes `key` and returns all of its values.
+            #[inline]
+            pub fn remove<Q>(&mut self, key: &Q) -> Option<VecOne<V>>
+            where
+                K: Borrow<Q>,
+                Q: $($bound)+ + ?Sized,
+            {
+                self.map.remove(key)
+            }
+
+            /// Removes the first value of `key` equal to `value`, removing the key if it was its last value.
+            pub fn remove_value<Q, R>(&mut self, key: &Q, value: &R) -> Option<V>
+            where
+                K: Borrow<Q>,
+                Q: $($bound)+ + ?Sized,
+                V: PartialEq<R>,
+                R: ?Sized,
+            {
+                let values = self.map.get_mut(key)?;
+                let index = values.iter().position(|v| v == value)?;
+
+                match values.remove(index) {
+                    Ok(removed) => Some(removed),
+                    Err(_) => {
+                        // The matching value is the only one left, so the whole key is removed.
+                        let ([removed], _) = self.map.remove(key)?.into_parts();
+                        Some(removed)
+                    }
+                }
+            }
+
+            /// Returns the entry of `key` in the underlying map.
+            ///
+            /// Values are stored as [`VecOne`]s, so no entry can ever be left empty.
+            #[inline]
+            pub fn entry(&mut self, key: K) -> $entry::Entry<'_, K, VecOne<V>> {
+                self.map.entry(key)
+            }
+        }
+
+        impl<K: $($bound)+, V: PartialEq> PartialEq for $name<K, V> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.map == other.map
+            }
+        }
+
+        impl<K: $($bound)+, V: Eq> Eq for $name<K, V> {}
+
+        impl<K, V> Default for $name<K, V> {
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<K: $($bound)+, V> Extend<(K, V)> for $name<K, V> {
+            #[inline]
+            fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+                for (key, value) in iter {
+                    self.insert(key, value);
+                }
+            }
+        }
+
+        impl<K: $($bound)+, V> FromIterator<(K, V)> for $name<K, V> {
+            #[inline]
+            fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+                let mut multimap = Self::new();
+                multimap.extend(iter);
+                multimap
+            }
+        }
+
+        impl<K, V> From<$name<K, V>> for $map<K, VecOne<V>> {
+            #[inline]
+            fn from(multimap: $name<K, V>) -> Self {
+                multimap.map
+            }
+        }
+
+        impl<K, V> From<$map<K, VecOne<V>>> for $name<K, V> {
+            #[inline]
+            fn from(map: $map<K, VecOne<V>>) -> Self {
+                Self { map }
+            }
+        }
+
+        impl<K, V> IntoIterator for $name<K, V> {
+            type Item = (K, VecOne<V>);
+            type IntoIter = $entry::IntoIter<K, VecOne<V>>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.map.into_iter()
+            }
+        }
+
+        impl<'a, K, V> IntoIterator for &'a $name<K, V> {
+            type Item = (&'a K, &'a VecOne<V>);
+            type IntoIter = $entry::Iter<'a, K, VecOne<V>>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.map.iter()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<K: serde::Serialize, V: serde::Serialize> serde::Serialize for $name<K, V> {
+            #[inline]
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serde::Serialize::serialize(&self.map, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, K, V> serde::Deserialize<'de> for $name<K, V>
+        where
+            K: serde::Deserialize<'de> + $($bound)+,
+            V: serde::Deserialize<'de> + core::fmt::Debug,
+        {
+            #[inline]
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                <$map<K, VecOne<V>> as serde::Deserialize>::deserialize(deserializer)
+                    .map(|map| Self { map })
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+multimap!(
+    /// A multimap backed by a `HashMap`, where every key holds a non-empty [`VecOne`] of values.
+    ///
+    /// Keys are removed automatically when their last value is removed. Requires the `std` feature.
+    MultiMapOne,
+    HashMap,
+    hash_map,
+    Hash + Eq
+);
+
+multimap!(
+    /// A multimap backed by a `BTreeMap`, where every key holds a non-empty [`VecOne`] of values.
+    ///
+    /// Keys are removed automatically when their last value is removed.
+    BTreeMultiMapOne,
+    BTreeMap,
+    btree_map,
+    Ord
+);
+
+#[cfg(test)]
+mod tests {
+    use super::BTreeMultiMapOne;
+    use crate::vecone;
+
+    #[test]
+    fn btree_multimap_one() {
+        let mut map: BTreeMultiMapOne<_, _> = [("a", 1), ("b", 2), ("a", 3)].into_iter().collect();
+        assert_eq!(map.get("a"), Some(&vecone![1, 3]));
+
+        assert_eq!(map.remove_value("a", &1), Some(1));
+        assert_eq!(map.remove_value("a", &1), None);
+        assert_eq!(map.remove_value("b", &2), Some(2));
+        assert!(!map.contains_key("b"));
+
+        map.entry("c").or_insert(vecone![4]).push(5);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.remove("c"), Some(vecone![4, 5]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn multimap_one() {
+        use super::MultiMapOne;
+
+        let mut map = MultiMapOne::new();
+        map.insert(1, 'a');
+        map.insert(1, 'b');
+        assert_eq!(map.remove_value(&1, &'a'), Some('a'));
+        assert_eq!(map.remove_value(&1, &'b'), Some('b'));
+        assert!(map.is_empty());
+    }
+}
@@ -0,0 +1,89 @@
+//! Operations that track the minimum length through their types, requires the `nightly` feature.
+
+use crate::VecMin;
+
+/// Concatenates `a` and `b`, returning a `VecMin` with a minimum of `A + B`.
+#[inline]
+pub fn concat<T, const A: usize, const B: usize>(
+    a: VecMin<T, A>,
+    b: VecMin<T, B>,
+) -> VecMin<T, { A + B }>
+where
+    [(); A + B]:,
+{
+    let mut vec = a.into_inner();
+    vec.append(&mut b.into_inner());
+
+    // Safety: The lengths of `a` and `b` are at least `A` and `B` respectively.
+    unsafe { VecMin::from_vec_unchecked(vec) }
+}
+
+impl<T, const M: usize> VecMin<T, M> {
+    /// Appends an element to the back of the vector, returning a `VecMin` with a minimum of `M + 1`.
+    #[inline]
+    pub fn push_grow(self, item: T) -> VecMin<T, { M + 1 }>
+    where
+        [(); M + 1]:,
+    {
+        let mut vec = self.into_inner();
+        vec.push(item);
+
+        // Safety: The length was at least `M` before pushing.
+        unsafe { VecMin::from_vec_unchecked(vec) }
+    }
+
+    /// Removes the last element of the vector, returning it and a `VecMin` with a minimum of `M - 1`.
+    #[inline]
+    pub fn pop_shrink(self) -> (VecMin<T, { M - 1 }>, T)
+    where
+        [(); M - 1]:,
+    {
+        let mut vec = self.into_inner();
+        let Some(item) = vec.pop() else {
+            unreachable!("a `VecMin` with a minimum of at least 1 is not empty")
+        };
+
+        // Safety: The length was at least `M` before popping.
+        (unsafe { VecMin::from_vec_unchecked(vec) }, item)
+    }
+
+    /// Splits the vector at `K`, returning a `VecMin` of the first `K` elements and a `VecMin` with a minimum of `M - K`
+    /// of the remaining elements.
+    #[inline]
+    pub fn split_at_const<const K: usize>(self) -> (VecMin<T, K>, VecMin<T, { M - K }>)
+    where
+        [(); M - K]:,
+    {
+        let mut head = self.into_inner();
+        let tail = head.split_off(K);
+
+        // Safety: `K` is at most `M`, so the head has exactly `K` elements and the tail at least `M - K`.
+        unsafe {
+            (
+                VecMin::from_vec_unchecked(head),
+                VecMin::from_vec_unchecked(tail),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::concat;
+    use crate::{VecMin, vecmin};
+
+    #[test]
+    fn const_arithmetic() {
+        let v: VecMin<_, 5> = concat(vecmin![1, 2], vecmin![3, 4]).push_grow(5);
+        assert_eq!(v, vec![1, 2, 3, 4, 5]);
+
+        let (v, last): (VecMin<_, 4>, _) = v.pop_shrink();
+        assert_eq!(last, 5);
+
+        let (head, tail): (VecMin<_, 1>, VecMin<_, 3>) = v.split_at_const::<1>();
+        assert_eq!(head, vec![1]);
+        assert_eq!(tail, vec![2, 3, 4]);
+    }
+}
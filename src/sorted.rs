@@ -0,0 +1,276 @@
+//! Implementation of the [`SortedVecMin`] sorted vector with a minimum length.
+
+use alloc::vec::{self, Vec};
+use core::borrow::Borrow;
+use core::ops::{Bound, Deref, RangeBounds};
+use core::slice;
+
+use crate::iter::TryFromIterator;
+use crate::vec::ConstructError;
+use crate::{ModifyError, VecMin};
+
+/// A [`VecMin`] whose elements are always sorted in ascending order.
+///
+/// Only read-only slice access is exposed, elements are added with [`SortedVecMin::insert`] which keeps the order.
+///
+/// `Ord` is not implemented so that [`SortedVecMin::min`] and [`SortedVecMin::max`] are not shadowed by [`Ord::min`]
+/// and [`Ord::max`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SortedVecMin<T, const M: usize> {
+    vec: VecMin<T, M>,
+}
+
+// --- Constructors, Convertors, and Destructors ---
+impl<T: Ord, const M: usize> SortedVecMin<T, M> {
+    /// Creates a new `SortedVecMin` by sorting a `VecMin`.
+    #[inline]
+    pub fn from_vec_min(mut vec: VecMin<T, M>) -> Self {
+        vec.sort();
+        Self { vec }
+    }
+
+    /// Creates a new `SortedVecMin` by sorting a `Vec`, returning an error if its length is less than `M`.
+    #[inline]
+    pub fn try_from_vec(vec: Vec<T>) -> Result<Self, ConstructError<T, M>> {
+        VecMin::try_from_vec(vec).map(Self::from_vec_min)
+    }
+
+    /// Creates a new `SortedVecMin` by sorting the elements of an iterator, returning an error if there are fewer than
+    /// `M`.
+    #[inline]
+    pub fn collect(iter: impl IntoIterator<Item = T>) -> Result<Self, ConstructError<T, M>> {
+        VecMin::collect(iter).map(Self::from_vec_min)
+    }
+}
+
+impl<T, const M: usize> SortedVecMin<T, M> {
+    /// Returns a reference to the underlying `VecMin`.
+    #[inline]
+    pub fn as_vec_min(&self) -> &VecMin<T, M> {
+        &self.vec
+    }
+
+    /// Returns the underlying `VecMin`, consuming the `SortedVecMin`.
+    #[inline]
+    pub fn into_vec_min(self) -> VecMin<T, M> {
+        self.vec
+    }
+
+    /// Returns the inner `Vec`, consuming the `SortedVecMin`.
+    #[inline]
+    pub fn into_inner(self) -> Vec<T> {
+        self.vec.into_inner()
+    }
+
+    /// See [`Vec::as_slice`].
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] {
+        self.vec.as_slice()
+    }
+
+    /// Returns a slice to the first `M` elements, which are the `M` smallest elements.
+    #[inline]
+    pub const fn min_slice(&self) -> &[T; M] {
+        self.vec.min_slice()
+    }
+
+    /// Returns the smallest element, `M` is checked at compile time to be at least 1.
+    #[inline]
+    pub const fn min(&self) -> &T {
+        &self.vec.first_chunk::<1>()[0]
+    }
+
+    /// Returns the largest element, `M` is checked at compile time to be at least 1.
+    #[inline]
+    pub const fn max(&self) -> &T {
+        &self.vec.last_chunk::<1>()[0]
+    }
+}
+
+impl<T, const M: usize> From<SortedVecMin<T, M>> for VecMin<T, M> {
+    #[inline]
+    fn from(sorted: SortedVecMin<T, M>) -> Self {
+        sorted.vec
+    }
+}
+
+impl<T, const M: usize> From<SortedVecMin<T, M>> for Vec<T> {
+    #[inline]
+    fn from(sorted: SortedVecMin<T, M>) -> Self {
+        sorted.vec.into_inner()
+    }
+}
+
+impl<T: Ord, const M: usize> From<VecMin<T, M>> for SortedVecMin<T, M> {
+    #[inline]
+    fn from(vec: VecMin<T, M>) -> Self {
+        Self::from_vec_min(vec)
+    }
+}
+
+impl<T: Ord, const M: usize> TryFrom<Vec<T>> for SortedVecMin<T, M> {
+    type Error = ConstructError<T, M>;
+
+    #[inline]
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        Self::try_from_vec(vec)
+    }
+}
+
+impl<T: Ord, const M: usize> TryFromIterator<T> for SortedVecMin<T, M> {
+    type Error = ConstructError<T, M>;
+
+    #[inline]
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Self::Error> {
+        Self::collect(iter)
+    }
+}
+
+// --- View ---
+impl<T, const M: usize> Deref for SortedVecMin<T, M> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.vec.as_slice()
+    }
+}
+
+impl<T, const M: usize> AsRef<[T]> for SortedVecMin<T, M> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.vec.as_slice()
+    }
+}
+
+// --- Iterators ---
+impl<T, const M: usize> IntoIterator for SortedVecMin<T, M> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+
+impl<'a, T: 'a, const M: usize> IntoIterator for &'a SortedVecMin<T, M> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.iter()
+    }
+}
+
+// --- Sorted Access ---
+impl<T: Ord, const M: usize> SortedVecMin<T, M> {
+    /// Inserts `value` after any equal elements, returning its index.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> usize {
+        let index = self.vec.partition_point(|x| *x <= value);
+        self.vec.insert(index, value);
+        index
+    }
+
+    /// Returns `true` if the vector contains an element equal to `value`.
+    #[inline]
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.vec.binary_search_by(|x| x.borrow().cmp(value)).is_ok()
+    }
+
+    /// Removes an element equal to `value`, returning it, or `None` if there is none.
+    ///
+    /// Returns an error if the operation would reduce the length of the vector below `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn remove_value<Q>(&mut self, value: &Q) -> Result<Option<T>, ModifyError<M>>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        match self.vec.binary_search_by(|x| x.borrow().cmp(value)) {
+            Ok(index) => self.vec.remove(index).map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Returns the sub-slice of elements within `range`.
+    #[inline]
+    pub fn range<Q, R>(&self, range: R) -> &[T]
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.vec.partition_point(|x| x.borrow() < start),
+            Bound::Excluded(start) => self.vec.partition_point(|x| x.borrow() <= start),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.vec.partition_point(|x| x.borrow() <= end),
+            Bound::Excluded(end) => self.vec.partition_point(|x| x.borrow() < end),
+            Bound::Unbounded => self.vec.len(),
+        };
+
+        &self.vec[start..end.max(start)]
+    }
+
+    /// Merges two sorted vectors in linear time, elements of `self` come before equal elements of `other`.
+    pub fn merge<const N: usize>(self, other: SortedVecMin<T, N>) -> Self {
+        let mut merged = Vec::with_capacity(self.vec.len() + other.vec.len());
+        let mut left = self.vec.into_iter().peekable();
+        let mut right = other.vec.into_iter().peekable();
+
+        while let (Some(l), Some(r)) = (left.peek(), right.peek()) {
+            let next = if r < l { right.next() } else { left.next() };
+            merged.extend(next);
+        }
+        merged.extend(left);
+        merged.extend(right);
+
+        Self {
+            // Safety: Every element of `self` is kept, so the length is at least `M`.
+            vec: unsafe { VecMin::from_vec_unchecked(merged) },
+        }
+    }
+}
+
+impl<T: Ord, const M: usize> Extend<T> for SortedVecMin<T, M> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.vec.extend(iter);
+        self.vec.sort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::SortedVecMin;
+    use crate::{ModifyError, TryFromIterator, vecmin};
+
+    #[test]
+    fn sorted_vec_min() {
+        let mut v = SortedVecMin::from(vecmin![2; [5, 1, 3]]);
+        assert_eq!(v.insert(4), 2);
+        assert_eq!((v.min(), v.max()), (&1, &5));
+        assert_eq!(v.range(2..=4), [3, 4]);
+        assert_eq!(v.range(6..), []);
+
+        assert_eq!(v.remove_value(&3), Ok(Some(3)));
+        assert_eq!(v.remove_value(&3), Ok(None));
+        assert_eq!(v.remove_value(&1), Ok(Some(1)));
+        assert_eq!(v.remove_value(&4), Err(ModifyError));
+
+        let other = SortedVecMin::<_, 1>::try_from_iter([6, 0, 4]).unwrap();
+        assert_eq!(v.merge(other).into_inner(), vec![0, 4, 4, 5, 6]);
+    }
+}
@@ -0,0 +1,511 @@
+//! Implementation of the [`ValidatedVec`] newtype, which generalises the minimum length of [`VecMin`] to any
+//! [`Invariant`].
+
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::marker::PhantomData;
+use core::mem;
+use core::ops::Deref;
+
+use crate::iter::TryFromIterator;
+use crate::{ModifyError, VecMin};
+
+/// How a category of operations interacts with an [`Invariant`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Check {
+    /// The operations can never break the invariant and are not checked.
+    Always,
+    /// The operations may break the invariant, so it is checked afterwards and the operation is undone if it fails.
+    Recheck,
+    /// The operations are rejected with [`InvariantError::Forbidden`].
+    Forbidden,
+}
+
+/// An invariant upheld by every [`ValidatedVec`].
+///
+/// Operations are grouped into categories, each of which declares whether it is always safe, must be re-checked, or
+/// is forbidden. Categories default to [`Check::Recheck`].
+///
+/// ```
+/// use vecmin::validated::{Check, Invariant, ValidatedVec};
+///
+/// struct Sorted;
+///
+/// impl<T: Ord> Invariant<T> for Sorted {
+///     type Error = ();
+///
+///     const SHRINK: Check = Check::Always;
+///
+///     fn check(slice: &[T]) -> Result<(), ()> {
+///         if slice.is_sorted() { Ok(()) } else { Err(()) }
+///     }
+/// }
+///
+/// let mut v = ValidatedVec::<_, Sorted>::try_new([1, 3]).unwrap();
+/// assert!(v.push(5).is_ok());
+/// assert!(v.push(4).is_err());
+/// assert_eq!(v.as_slice(), [1, 3, 5]);
+/// ```
+pub trait Invariant<T> {
+    /// The error returned when the invariant does not hold.
+    type Error;
+
+    /// How operations that only add elements, such as `push` and `insert`, are handled.
+    const GROW: Check = Check::Recheck;
+
+    /// How operations that only remove elements, such as `pop` and `remove`, are handled.
+    const SHRINK: Check = Check::Recheck;
+
+    /// How operations that replace elements in place, such as `replace` and `swap`, are handled.
+    const MUTATE: Check = Check::Recheck;
+
+    /// Checks that the invariant holds for `slice`.
+    fn check(slice: &[T]) -> Result<(), Self::Error>;
+}
+
+/// The invariant of [`VecMin`], a length of at least `M`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MinLen<const M: usize>;
+
+impl<T, const M: usize> Invariant<T> for MinLen<M> {
+    type Error = ModifyError<M>;
+
+    const GROW: Check = Check::Always;
+    const MUTATE: Check = Check::Always;
+
+    #[inline]
+    fn check(slice: &[T]) -> Result<(), ModifyError<M>> {
+        if slice.len() >= M {
+            Ok(())
+        } else {
+            Err(ModifyError)
+        }
+    }
+}
+
+/// An error indicating that an operation on a [`ValidatedVec`] was rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InvariantError<E> {
+    /// The operation is forbidden by the invariant.
+    Forbidden,
+    /// The operation would break the invariant and was undone.
+    Violated(E),
+}
+
+impl<E: Display> Display for InvariantError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Forbidden => f.write_str("operation is forbidden by the invariant"),
+            Self::Violated(error) => write!(f, "operation would break the invariant: {error}"),
+        }
+    }
+}
+
+impl<E: Debug + Display> Error for InvariantError<E> {}
+
+/// A vector that does not satisfy the invariant returned as an error in a constructor.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ValidateError<T, E> {
+    /// The rejected vector.
+    pub vec: Vec<T>,
+    /// The reason the vector was rejected.
+    pub error: E,
+}
+
+impl<T, E: Display> Display for ValidateError<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "vector does not satisfy the invariant: {}", self.error)
+    }
+}
+
+impl<T: Debug, E: Debug + Display> Error for ValidateError<T, E> {}
+
+/// A vector whose elements always satisfy the invariant `P`.
+///
+/// Only read-only slice access is exposed, every mutating method is gated on the [`Check`] the invariant declares for
+/// it.
+pub struct ValidatedVec<T, P: Invariant<T>> {
+    vec: Vec<T>,
+    invariant: PhantomData<fn() -> P>,
+}
+
+// --- Constructors, Convertors, and Destructors ---
+impl<T, P: Invariant<T>> ValidatedVec<T, P> {
+    /// Creates a new `ValidatedVec` from a `Vec`.
+    ///
+    /// # Safety
+    /// - The `Vec` must satisfy the invariant `P`.
+    #[inline]
+    pub const unsafe fn from_vec_unchecked(vec: Vec<T>) -> Self {
+        Self {
+            vec,
+            invariant: PhantomData,
+        }
+    }
+
+    /// Creates a new `ValidatedVec` from a `Vec`, returning an error if the `Vec` does not satisfy the invariant.
+    #[inline]
+    pub fn try_from_vec(vec: Vec<T>) -> Result<Self, ValidateError<T, P::Error>> {
+        match P::check(&vec) {
+            // Safety: We just checked the invariant.
+            Ok(()) => Ok(unsafe { Self::from_vec_unchecked(vec) }),
+            Err(error) => Err(ValidateError { vec, error }),
+        }
+    }
+
+    /// Creates a new `ValidatedVec` from anything that can be converted into a `Vec`, returning an error if the `Vec`
+    /// does not satisfy the invariant.
+    #[inline]
+    pub fn try_new(vec: impl Into<Vec<T>>) -> Result<Self, ValidateError<T, P::Error>> {
+        Self::try_from_vec(vec.into())
+    }
+
+    /// Creates a new `ValidatedVec` from an iterator, returning an error if the collected `Vec` does not satisfy the
+    /// invariant.
+    #[inline]
+    pub fn collect(iter: impl IntoIterator<Item = T>) -> Result<Self, ValidateError<T, P::Error>> {
+        Self::try_from_vec(iter.into_iter().collect())
+    }
+
+    /// Returns the inner `Vec`, consuming the `ValidatedVec`.
+    #[inline]
+    pub fn into_inner(self) -> Vec<T> {
+        self.vec
+    }
+}
+
+impl<T, const M: usize> From<VecMin<T, M>> for ValidatedVec<T, MinLen<M>> {
+    #[inline]
+    fn from(vec_min: VecMin<T, M>) -> Self {
+        // Safety: A `VecMin<T, M>` has a length of at least `M`.
+        unsafe { Self::from_vec_unchecked(vec_min.into_inner()) }
+    }
+}
+
+impl<T, const M: usize> From<ValidatedVec<T, MinLen<M>>> for VecMin<T, M> {
+    #[inline]
+    fn from(validated: ValidatedVec<T, MinLen<M>>) -> Self {
+        // Safety: `MinLen<M>` guarantees a length of at least `M`.
+        unsafe { VecMin::from_vec_unchecked(validated.vec) }
+    }
+}
+
+impl<T, P: Invariant<T>> From<ValidatedVec<T, P>> for Vec<T> {
+    #[inline]
+    fn from(validated: ValidatedVec<T, P>) -> Self {
+        validated.vec
+    }
+}
+
+impl<T, P: Invariant<T>> TryFrom<Vec<T>> for ValidatedVec<T, P> {
+    type Error = ValidateError<T, P::Error>;
+
+    #[inline]
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        Self::try_from_vec(vec)
+    }
+}
+
+impl<T, P: Invariant<T>> TryFromIterator<T> for ValidatedVec<T, P> {
+    type Error = ValidateError<T, P::Error>;
+
+    #[inline]
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Self::Error> {
+        Self::collect(iter)
+    }
+}
+
+// --- View ---
+impl<T, P: Invariant<T>> ValidatedVec<T, P> {
+    /// See [`Vec::as_slice`].
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] {
+        self.vec.as_slice()
+    }
+
+    /// Returns a reference to the inner `Vec`.
+    #[inline]
+    pub fn vec(&self) -> &Vec<T> {
+        &self.vec
+    }
+
+    /// See [`Vec::capacity`].
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    /// See [`Vec::reserve`].
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec.reserve(additional);
+    }
+
+    /// See [`Vec::shrink_to_fit`].
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.vec.shrink_to_fit();
+    }
+}
+
+impl<T, P: Invariant<T>> Deref for ValidatedVec<T, P> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.vec.deref()
+    }
+}
+
+impl<T, P: Invariant<T>> AsRef<[T]> for ValidatedVec<T, P> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.vec.as_ref()
+    }
+}
+
+impl<T, P: Invariant<T>> IntoIterator for ValidatedVec<T, P> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+
+impl<'a, T: 'a, P: Invariant<T>> IntoIterator for &'a ValidatedVec<T, P> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.iter()
+    }
+}
+
+// --- Mutable Access ---
+impl<T, P: Invariant<T>> ValidatedVec<T, P> {
+    /// Applies `op` according to `check`, undoing it with `undo` if the invariant no longer holds.
+    #[inline]
+    fn apply<R>(
+        &mut self,
+        check: Check,
+        op: impl FnOnce(&mut Vec<T>) -> R,
+        undo: impl FnOnce(&mut Vec<T>, R),
+    ) -> Result<R, InvariantError<P::Error>> {
+        match check {
+            Check::Always => Ok(op(&mut self.vec)),
+            Check::Forbidden => Err(InvariantError::Forbidden),
+            Check::Recheck => {
+                let result = op(&mut self.vec);
+                match P::check(&self.vec) {
+                    Ok(()) => Ok(result),
+                    Err(error) => {
+                        undo(&mut self.vec, result);
+                        Err(InvariantError::Violated(error))
+                    }
+                }
+            }
+        }
+    }
+
+    /// See [`Vec::push`]. Returns an error and drops `item` if the invariant would not hold.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn push(&mut self, item: T) -> Result<(), InvariantError<P::Error>> {
+        self.apply(
+            P::GROW,
+            |vec| vec.push(item),
+            |vec, ()| {
+                vec.pop();
+            },
+        )
+    }
+
+    /// See [`Vec::insert`]. Returns an error and drops `element` if the invariant would not hold.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn insert(&mut self, index: usize, element: T) -> Result<(), InvariantError<P::Error>> {
+        self.apply(
+            P::GROW,
+            |vec| vec.insert(index, element),
+            |vec, ()| {
+                vec.remove(index);
+            },
+        )
+    }
+
+    /// See [`Vec::pop`]. Returns an error if the invariant would not hold.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn pop(&mut self) -> Result<Option<T>, InvariantError<P::Error>> {
+        self.apply(P::SHRINK, Vec::pop, |vec, item| vec.extend(item))
+    }
+
+    /// See [`Vec::remove`]. Returns an error if the invariant would not hold.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn remove(&mut self, index: usize) -> Result<T, InvariantError<P::Error>> {
+        self.apply(
+            P::SHRINK,
+            |vec| vec.remove(index),
+            |vec, item| vec.insert(index, item),
+        )
+    }
+
+    /// See [`Vec::truncate`]. Returns an error if the invariant would not hold.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn truncate(&mut self, len: usize) -> Result<(), InvariantError<P::Error>> {
+        let at = len.min(self.vec.len());
+        self.apply(
+            P::SHRINK,
+            |vec| vec.split_off(at),
+            |vec, mut tail| vec.append(&mut tail),
+        )
+        .map(drop)
+    }
+
+    /// See [`Vec::clear`]. Returns an error if the invariant would not hold.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn clear(&mut self) -> Result<(), InvariantError<P::Error>> {
+        self.truncate(0)
+    }
+
+    /// Replaces the element at `index`, returning the previous one, or an error if the invariant would not hold.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn replace(&mut self, index: usize, element: T) -> Result<T, InvariantError<P::Error>> {
+        self.apply(
+            P::MUTATE,
+            |vec| mem::replace(&mut vec[index], element),
+            |vec, previous| vec[index] = previous,
+        )
+    }
+
+    /// See [`slice::swap`]. Returns an error if the invariant would not hold.
+    ///
+    /// # Panics
+    /// Panics if `a` or `b` are out of bounds.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn swap(&mut self, a: usize, b: usize) -> Result<(), InvariantError<P::Error>> {
+        self.apply(P::MUTATE, |vec| vec.swap(a, b), |vec, ()| vec.swap(a, b))
+    }
+}
+
+// --- Traits ---
+impl<T: Clone, P: Invariant<T>> Clone for ValidatedVec<T, P> {
+    #[inline]
+    fn clone(&self) -> Self {
+        // Safety: A clone satisfies the same invariant.
+        unsafe { Self::from_vec_unchecked(self.vec.clone()) }
+    }
+}
+
+impl<T: Debug, P: Invariant<T>> Debug for ValidatedVec<T, P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.vec, f)
+    }
+}
+
+impl<T: PartialEq, P: Invariant<T>> PartialEq for ValidatedVec<T, P> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, P: Invariant<T>> Eq for ValidatedVec<T, P> {}
+
+impl<T: PartialEq, P: Invariant<T>> PartialEq<Vec<T>> for ValidatedVec<T, P> {
+    #[inline]
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::{Check, Invariant, InvariantError, MinLen, ValidatedVec};
+    use crate::{ModifyError, VecMin, vecmin};
+
+    /// Elements in ascending order, which no in-place mutation is trusted to keep.
+    struct Sorted;
+
+    impl<T: Ord> Invariant<T> for Sorted {
+        type Error = ();
+
+        const MUTATE: Check = Check::Forbidden;
+
+        fn check(slice: &[T]) -> Result<(), ()> {
+            if slice.is_sorted() { Ok(()) } else { Err(()) }
+        }
+    }
+
+    /// A last element of zero, checked after every operation.
+    struct ZeroTerminated;
+
+    impl Invariant<i32> for ZeroTerminated {
+        type Error = ();
+
+        fn check(slice: &[i32]) -> Result<(), ()> {
+            if slice.last() == Some(&0) {
+                Ok(())
+            } else {
+                Err(())
+            }
+        }
+    }
+
+    #[test]
+    fn min_len() {
+        assert!(ValidatedVec::<_, MinLen<2>>::try_new([1]).is_err());
+
+        let mut v = ValidatedVec::from(vecmin![1, 2]);
+        assert_eq!(v.push(3), Ok(()));
+        assert_eq!(v.remove(0), Ok(1));
+        assert_eq!(v.pop(), Err(InvariantError::Violated(ModifyError)));
+        assert_eq!(v.truncate(1), Err(InvariantError::Violated(ModifyError)));
+        assert_eq!(v.replace(0, 4), Ok(2));
+        assert_eq!(v, vec![4, 3]);
+
+        assert_eq!(VecMin::from(v), vecmin![4, 3]);
+    }
+
+    #[test]
+    fn sorted() {
+        let mut v = ValidatedVec::<_, Sorted>::try_new([1, 3]).unwrap();
+        assert_eq!(v.push(0), Err(InvariantError::Violated(())));
+        assert_eq!(v.insert(0, 4), Err(InvariantError::Violated(())));
+        assert_eq!(v.insert(1, 2), Ok(()));
+        assert_eq!(v, vec![1, 2, 3]);
+
+        assert_eq!(v.replace(0, 0), Err(InvariantError::Forbidden));
+        assert_eq!(v.swap(0, 0), Err(InvariantError::Forbidden));
+        assert_eq!(v.remove(1), Ok(2));
+        assert_eq!(v, vec![1, 3]);
+    }
+
+    #[test]
+    fn recheck_undo() {
+        let mut v = ValidatedVec::<_, ZeroTerminated>::try_new([1, 2, 0, 3, 0]).unwrap();
+        assert_eq!(v.truncate(4), Err(InvariantError::Violated(())));
+        assert_eq!(v, vec![1, 2, 0, 3, 0]);
+        assert_eq!(v.truncate(3), Ok(()));
+
+        assert_eq!(v.swap(0, 2), Err(InvariantError::Violated(())));
+        assert_eq!(v.swap(0, 1), Ok(()));
+        assert_eq!(v.pop(), Err(InvariantError::Violated(())));
+        assert_eq!(v.clear(), Err(InvariantError::Violated(())));
+        assert_eq!(v, vec![2, 1, 0]);
+    }
+}
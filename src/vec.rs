@@ -9,11 +9,11 @@ use core::cmp::Ordering;
 use core::error::Error;
 use core::fmt::{self, Debug, Display, Formatter};
 use core::iter::repeat_with;
-use core::mem::MaybeUninit;
+use core::mem::{self, MaybeUninit};
 use core::ops::{Deref, DerefMut, RangeBounds};
-use core::slice;
+use core::{ptr, slice};
 
-use crate::{ModifyError, slice_range};
+use crate::{Assert, AssertMin, AssertProduct, AssertSum, ModifyError, SplitError, slice_range};
 
 /// A [`VecMin`] with a minimum length of 1.
 pub type VecOne<T> = VecMin<T, 1>;
@@ -88,6 +88,98 @@ impl<T, const M: usize> VecMin<T, M> {
         let min = unsafe { &mut *(min.as_mut_ptr() as *mut [T; M]) };
         (min, extra)
     }
+
+    /// Returns a tuple of a slice to the elements before the last `M` elements of the vector, and a slice to the last `M` elements of the vector, which are guaranteed to exist.
+    #[inline]
+    pub const fn split_last_min(&self) -> (&[T], &[T; M]) {
+        self.debug_assert_invariant();
+
+        let (extra, min) = unsafe { self.vec.as_slice().split_at_unchecked(self.vec.len() - M) };
+        let min = unsafe { &*(min.as_ptr() as *const [T; M]) };
+        (extra, min)
+    }
+
+    /// Returns a tuple of a mutable slice to the elements before the last `M` elements of the vector, and a mutable slice to the last `M` elements of the vector, which are guaranteed to exist.
+    #[inline]
+    pub const fn split_last_min_mut(&mut self) -> (&mut [T], &mut [T; M]) {
+        self.debug_assert_invariant();
+
+        let mid = self.vec.len() - M;
+        let (extra, min) = unsafe { self.vec.as_mut_slice().split_at_mut_unchecked(mid) };
+        let min = unsafe { &mut *(min.as_mut_ptr() as *mut [T; M]) };
+        (extra, min)
+    }
+
+    /// Returns a reference to the element at index `I`, which is checked at compile time to be less than `M`.
+    #[inline]
+    pub const fn at<const I: usize>(&self) -> &T {
+        let () = Assert::<I, M>::INDEX;
+        &self.min_slice()[I]
+    }
+
+    /// Returns a mutable reference to the element at index `I`, which is checked at compile time to be less than `M`.
+    #[inline]
+    pub const fn at_mut<const I: usize>(&mut self) -> &mut T {
+        let () = Assert::<I, M>::INDEX;
+        &mut self.min_slice_mut()[I]
+    }
+
+    /// See [`slice::first_chunk`]. Returns the first `K` elements, where `K` is checked at compile time to be at most `M`.
+    ///
+    /// This shadows the slice method, which returns `None` if `K` is greater than the length. `K > M` is a compile
+    /// error instead, use `v.as_slice().first_chunk()` for the `Option` form.
+    #[inline]
+    pub const fn first_chunk<const K: usize>(&self) -> &[T; K] {
+        let () = Assert::<K, M>::CHUNK;
+        unsafe { &*(self.vec.as_ptr() as *const [T; K]) }
+    }
+
+    /// See [`slice::first_chunk_mut`]. Returns the first `K` elements, where `K` is checked at compile time to be at most `M`.
+    ///
+    /// This shadows the slice method, which returns `None` if `K` is greater than the length. `K > M` is a compile
+    /// error instead, use `v.as_mut_slice().first_chunk_mut()` for the `Option` form.
+    #[inline]
+    pub const fn first_chunk_mut<const K: usize>(&mut self) -> &mut [T; K] {
+        let () = Assert::<K, M>::CHUNK;
+        unsafe { &mut *(self.vec.as_mut_ptr() as *mut [T; K]) }
+    }
+
+    /// See [`slice::last_chunk`]. Returns the last `K` elements, where `K` is checked at compile time to be at most `M`.
+    ///
+    /// This shadows the slice method, which returns `None` if `K` is greater than the length. `K > M` is a compile
+    /// error instead, use `v.as_slice().last_chunk()` for the `Option` form.
+    #[inline]
+    pub const fn last_chunk<const K: usize>(&self) -> &[T; K] {
+        let () = Assert::<K, M>::CHUNK;
+        self.debug_assert_invariant();
+
+        unsafe { &*(self.vec.as_ptr().add(self.vec.len() - K) as *const [T; K]) }
+    }
+
+    /// See [`slice::last_chunk_mut`]. Returns the last `K` elements, where `K` is checked at compile time to be at most `M`.
+    ///
+    /// This shadows the slice method, which returns `None` if `K` is greater than the length. `K > M` is a compile
+    /// error instead, use `v.as_mut_slice().last_chunk_mut()` for the `Option` form.
+    #[inline]
+    pub const fn last_chunk_mut<const K: usize>(&mut self) -> &mut [T; K] {
+        let () = Assert::<K, M>::CHUNK;
+        self.debug_assert_invariant();
+
+        let len = self.vec.len();
+        unsafe { &mut *(self.vec.as_mut_ptr().add(len - K) as *mut [T; K]) }
+    }
+
+    /// Returns a slice to the elements after the first `M` elements of the vector.
+    #[inline]
+    pub const fn extra_slice(&self) -> &[T] {
+        self.split_at_min().1
+    }
+
+    /// Returns a mutable slice to the elements after the first `M` elements of the vector.
+    #[inline]
+    pub const fn extra_slice_mut(&mut self) -> &mut [T] {
+        self.split_at_min_mut().1
+    }
 }
 
 // --- Constructors, Convertors, and Destructors ---
@@ -143,13 +235,34 @@ impl<T, const M: usize> VecMin<T, M> {
         unsafe { Self::from_vec_unchecked(array.into()) }
     }
 
+    /// Creates a new `VecMin` from an array of any length `N`, which is checked at compile time to be at least `M`.
+    #[inline]
+    pub fn from_array_n<const N: usize>(array: [T; N]) -> Self {
+        let () = Assert::<M, N>::ARRAY_LEN;
+
+        // Safety: An array of length `N` has a length of at least `M`.
+        unsafe { Self::from_vec_unchecked(array.into()) }
+    }
+
+    /// Creates a new `VecMin` by cloning an array of any length `N`, which is checked at compile time to be at least `M`.
+    #[inline]
+    pub fn from_array_ref_n<const N: usize>(array: &[T; N]) -> Self
+    where
+        T: Clone,
+    {
+        let () = Assert::<M, N>::ARRAY_LEN;
+
+        // Safety: An array of length `N` has a length of at least `M`.
+        unsafe { Self::from_vec_unchecked(array.to_vec()) }
+    }
+
     /// Creates a new `VecMin` from an iterator, returning an error if the length of the collected `Vec` is less than `M`.
     #[inline]
     pub fn collect(iter: impl IntoIterator<Item = T>) -> Result<Self, ConstructError<T, M>> {
         let iter = iter.into_iter();
-        let (low, high) = iter.size_hint();
+        let (low, _) = iter.size_hint();
 
-        Self::collect_with_capacity(iter, high.unwrap_or(low).max(M))
+        Self::collect_with_capacity(iter, low.max(M))
     }
 
     /// Creates a new `VecMin` from an iterator, returning an error if the length of the collected `Vec` is less than `M`.
@@ -165,6 +278,138 @@ impl<T, const M: usize> VecMin<T, M> {
         Self::try_new(vec)
     }
 
+    /// Creates a new `VecMin` from a `Vec`, padding it to `M` with clones of `value` if it is too short.
+    #[inline]
+    pub fn from_vec_padded(mut vec: Vec<T>, value: T) -> Self
+    where
+        T: Clone,
+    {
+        if vec.len() < M {
+            vec.reserve_exact(M - vec.len());
+            vec.resize(M, value);
+        }
+
+        // Safety: The `Vec` was padded to at least `M` elements.
+        unsafe { Self::from_vec_unchecked(vec) }
+    }
+
+    /// Creates a new `VecMin` from a `Vec`, padding it to `M` with elements generated by `f` if it is too short.
+    #[inline]
+    pub fn from_vec_padded_with(mut vec: Vec<T>, f: impl FnMut() -> T) -> Self {
+        if vec.len() < M {
+            vec.reserve_exact(M - vec.len());
+            vec.resize_with(M, f);
+        }
+
+        // Safety: The `Vec` was padded to at least `M` elements.
+        unsafe { Self::from_vec_unchecked(vec) }
+    }
+
+    /// Creates a new `VecMin` from an iterator, padding it to `M` with elements generated by `f` if it is too short.
+    #[inline]
+    pub fn from_iter_padded(iter: impl IntoIterator<Item = T>, f: impl FnMut() -> T) -> Self {
+        let iter = iter.into_iter();
+        let (low, _) = iter.size_hint();
+
+        let mut vec = Vec::with_capacity(low.max(M));
+        vec.extend(iter);
+
+        Self::from_vec_padded_with(vec, f)
+    }
+
+    /// Creates a new `VecMin` from `n` clones of `elem`, returning an error if `n` is less than `M`.
+    #[inline]
+    pub fn repeat(elem: T, n: usize) -> Result<Self, ConstructError<T, M>>
+    where
+        T: Clone,
+    {
+        Self::try_from_vec(alloc::vec![elem; n])
+    }
+
+    /// Creates a new `VecMin` of `n` elements generated by calling `f` with each index, returning an error if `n` is less than `M`.
+    #[inline]
+    pub fn from_fn(n: usize, f: impl FnMut(usize) -> T) -> Result<Self, ConstructError<T, M>> {
+        Self::try_from_vec((0..n).map(f).collect())
+    }
+
+    /// Creates a new `VecMin` from a `Vec`, filling it to `M` by cycling through its elements if it is too short.
+    ///
+    /// Returns an error if the `Vec` is empty and `M` is not zero.
+    #[inline]
+    pub fn from_vec_cycled(mut vec: Vec<T>) -> Result<Self, ConstructError<T, M>>
+    where
+        T: Clone,
+    {
+        let len = vec.len();
+
+        if len == 0 {
+            return Self::try_from_vec(vec);
+        }
+        if len < M {
+            vec.reserve_exact(M - len);
+            for i in len..M {
+                vec.push(vec[i % len].clone());
+            }
+        }
+
+        // Safety: The `Vec` was filled to at least `M` elements.
+        Ok(unsafe { Self::from_vec_unchecked(vec) })
+    }
+
+    /// Creates a new `VecMin` from an array of the minimum elements followed by a `Vec` of extra elements.
+    ///
+    /// The allocation of `extra` is reused.
+    #[inline]
+    pub fn from_parts(min: [T; M], mut extra: Vec<T>) -> Self {
+        extra.splice(0..0, min);
+
+        // Safety: `extra` now starts with the `M` elements of `min`.
+        unsafe { Self::from_vec_unchecked(extra) }
+    }
+
+    /// Splits the `VecMin` into an array of the first `M` elements and a `Vec` of the remaining elements.
+    ///
+    /// The allocation of the `VecMin` is reused for the remaining elements.
+    #[inline]
+    pub fn into_parts(self) -> ([T; M], Vec<T>) {
+        self.debug_assert_invariant();
+
+        let mut vec = self.vec;
+        let len = vec.len();
+
+        // Safety: The first `M` elements exist and are moved out before the remaining elements are shifted over them.
+        // Nothing in between can panic, so the elements are never observed twice.
+        unsafe {
+            vec.set_len(0);
+            let ptr = vec.as_mut_ptr();
+            let min = ptr::read(ptr as *const [T; M]);
+            ptr::copy(ptr.add(M), ptr, len - M);
+            vec.set_len(len - M);
+            (min, vec)
+        }
+    }
+
+    /// Returns an array of the first `M` elements, dropping the remaining elements.
+    #[inline]
+    pub fn into_min_array(self) -> [T; M] {
+        let mut vec = self.vec;
+        vec.truncate(M);
+
+        match vec.try_into() {
+            Ok(array) => array,
+            Err(_) => unreachable!("a `VecMin` has at least `M` elements"),
+        }
+    }
+
+    /// Converts the `VecMin` into an array of length `N`, returning the `VecMin` as an error if its length is not exactly `N`.
+    ///
+    /// `N` is checked at compile time to be at least `M`, since shorter lengths can never match.
+    #[inline]
+    pub fn into_array_exact<const N: usize>(self) -> Result<[T; N], Self> {
+        let () = Assert::<M, N>::ARRAY_LEN;
+        self.try_into()
+    }
+
     /// Returns the inner `Vec`, consuming the `VecMin`.
     #[inline]
     pub fn into_inner(self) -> Vec<T> {
@@ -190,6 +435,70 @@ impl<T, const M: usize> VecMin<T, M> {
     }
 }
 
+impl<T> VecOne<T> {
+    /// Creates a new `VecOne` containing only `head`.
+    #[inline]
+    pub fn new(head: T) -> Self {
+        Self::from_array([head])
+    }
+
+    /// Creates a new `VecOne` from its first element followed by the remaining elements.
+    ///
+    /// The allocation of `tail` is reused.
+    #[inline]
+    pub fn from_head_tail(head: T, tail: Vec<T>) -> Self {
+        Self::from_parts([head], tail)
+    }
+
+    /// Splits the `VecOne` into its first element and a `Vec` of the remaining elements.
+    ///
+    /// The allocation of the `VecOne` is reused for the remaining elements.
+    #[inline]
+    pub fn into_head_tail(self) -> (T, Vec<T>) {
+        let ([head], tail) = self.into_parts();
+        (head, tail)
+    }
+
+    /// Returns the `VecOne` with `head` prepended.
+    #[inline]
+    pub fn cons(mut self, head: T) -> Self {
+        self.vec.insert(0, head);
+        self
+    }
+
+    /// Splits the `VecOne` into its first element and a `Vec` of the remaining elements, see [`VecOne::into_head_tail`].
+    #[inline]
+    pub fn uncons(self) -> (T, Vec<T>) {
+        self.into_head_tail()
+    }
+
+    /// Returns a reference to the first element, which is guaranteed to exist.
+    #[inline]
+    pub const fn head(&self) -> &T {
+        let ([head], _) = self.split_at_min();
+        head
+    }
+
+    /// Returns a mutable reference to the first element, which is guaranteed to exist.
+    #[inline]
+    pub const fn head_mut(&mut self) -> &mut T {
+        let ([head], _) = self.split_at_min_mut();
+        head
+    }
+
+    /// Returns a slice to the elements after the first element.
+    #[inline]
+    pub const fn tail(&self) -> &[T] {
+        self.split_at_min().1
+    }
+
+    /// Returns a mutable slice to the elements after the first element.
+    #[inline]
+    pub const fn tail_mut(&mut self) -> &mut [T] {
+        self.split_at_min_mut().1
+    }
+}
+
 impl<T: Default, const M: usize> Default for VecMin<T, M> {
     #[inline]
     fn default() -> Self {
@@ -421,6 +730,60 @@ impl<T, const M: usize> VecMin<T, M> {
     }
 }
 
+// --- Windows & Chunks ---
+impl<T, const M: usize> VecMin<T, M> {
+    /// See [`slice::windows`]. Returns an iterator over all contiguous windows of length `K` as arrays.
+    ///
+    /// There is always at least one window since `K` is checked at compile time to be in `1..=M`.
+    #[inline]
+    pub fn array_windows<const K: usize>(
+        &self,
+    ) -> impl ExactSizeIterator<Item = &[T; K]> + DoubleEndedIterator {
+        let () = Assert::<K, M>::WINDOW;
+
+        // Safety: Every window has a length of exactly `K`.
+        self.vec
+            .windows(K)
+            .map(|window| unsafe { &*(window.as_ptr() as *const [T; K]) })
+    }
+
+    /// See [`slice::chunks_exact`]. Returns an iterator over chunks of length `K` as arrays, the remainder is not yielded.
+    ///
+    /// There is always at least one chunk since `K` is checked at compile time to be in `1..=M`.
+    #[inline]
+    pub fn array_chunks<const K: usize>(
+        &self,
+    ) -> impl ExactSizeIterator<Item = &[T; K]> + DoubleEndedIterator {
+        let () = Assert::<K, M>::ARRAY_CHUNK;
+
+        // Safety: Every chunk has a length of exactly `K`.
+        self.vec
+            .chunks_exact(K)
+            .map(|chunk| unsafe { &*(chunk.as_ptr() as *const [T; K]) })
+    }
+
+    /// Returns an iterator over all pairs of adjacent elements, requiring `M` to be at least 2 at compile time.
+    #[inline]
+    pub fn pairwise(&self) -> impl ExactSizeIterator<Item = (&T, &T)> + DoubleEndedIterator {
+        self.array_windows::<2>().map(|[a, b]| (a, b))
+    }
+
+    /// See [`slice::chunks`]. Returns the chunks of length `chunk_size`, the last chunk may be shorter.
+    ///
+    /// There is always at least one chunk since `M` is checked at compile time to be at least 1.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is zero.
+    #[inline]
+    #[track_caller]
+    pub fn chunks_min(&self, chunk_size: usize) -> VecOne<&[T]> {
+        let () = AssertMin::<M>::NON_EMPTY;
+
+        // Safety: The vector is not empty, so there is at least one chunk.
+        unsafe { VecOne::from_vec_unchecked(self.vec.chunks(chunk_size).collect()) }
+    }
+}
+
 // --- Mutable Access ---
 
 // -- Not Len Decreasing --
@@ -484,6 +847,13 @@ impl<T, const M: usize> VecMin<T, M> {
         self.vec.append(other);
     }
 
+    /// See [`Vec::append`]. Moves all elements of another `VecMin` to the end of the vector.
+    #[inline]
+    pub fn append_vecmin<const N: usize>(&mut self, other: VecMin<T, N>) {
+        let mut other = other.vec;
+        self.vec.append(&mut other);
+    }
+
     /// See [`Vec::extend_from_slice`].
     #[inline]
     pub fn extend_from_slice(&mut self, other: &[T])
@@ -523,55 +893,34 @@ impl<T, const M: usize> VecMin<T, M> {
     /// See [`Vec::pop`]. Pops an element from the vector if the length of the vector is greater than `M`, otherwise does nothing and returns `None`.
     #[inline]
     pub fn pop_to_min(&mut self) -> Option<T> {
-        if self.vec.len() > M {
-            self.vec.pop()
-        } else {
-            None
-        }
+        pop_to_min(&mut self.vec, M)
     }
 
     /// See [`Vec::pop_if`]. Pops an element from the vector if the length of the vector is greater than `M` and the provided predicate returns `true`, otherwise does nothing and returns `None`.
     #[inline]
     pub fn pop_to_min_if(&mut self, pred: impl FnOnce(&mut T) -> bool) -> Option<T> {
-        if self.vec.len() > M {
-            self.vec.pop_if(pred)
-        } else {
-            None
-        }
+        pop_to_min_if(&mut self.vec, M, pred)
     }
 
     /// See [`Vec::remove`]. Returns an error if the operation would reduce the length of the vector below `M`.
     #[inline]
     #[must_use = "this operation may fail"]
     pub fn remove(&mut self, index: usize) -> Result<T, ModifyError<M>> {
-        if self.vec.len() > M {
-            Ok(self.vec.remove(index))
-        } else {
-            Err(ModifyError)
-        }
+        remove(&mut self.vec, M, index).ok_or(ModifyError)
     }
 
     /// See [`Vec::swap_remove`]. Returns an error if the operation would reduce the length of the vector below `M`.
     #[inline]
     #[must_use = "this operation may fail"]
     pub fn swap_remove(&mut self, index: usize) -> Result<T, ModifyError<M>> {
-        if self.vec.len() > M {
-            Ok(self.vec.swap_remove(index))
-        } else {
-            Err(ModifyError)
-        }
+        swap_remove(&mut self.vec, M, index).ok_or(ModifyError)
     }
 
     /// See [`Vec::truncate`]. Returns an error if the operation would reduce the length of the vector below `M`.
     #[inline]
     #[must_use = "this operation may fail"]
     pub fn truncate(&mut self, len: usize) -> Result<(), ModifyError<M>> {
-        if len >= M {
-            self.vec.truncate(len);
-            Ok(())
-        } else {
-            Err(ModifyError)
-        }
+        truncate(&mut self.vec, M, len).ok_or(ModifyError)
     }
 
     /// See [`Vec::truncate`]. Truncates the vector to `len` if `len` is greater than or equal to `M`, otherwise truncates the vector to `M`.
@@ -586,6 +935,12 @@ impl<T, const M: usize> VecMin<T, M> {
         self.vec.truncate(M);
     }
 
+    /// See [`Vec::clear`]. An alias of [`VecMin::truncate_to_min`], removing all elements after the first `M`.
+    #[inline]
+    pub fn clear_to_min(&mut self) {
+        self.truncate_to_min();
+    }
+
     /// See [`Vec::resize`]. Returns an error if the operation would reduce the length of the vector below `M`.
     #[inline]
     #[must_use = "this operation may fail"]
@@ -593,12 +948,7 @@ impl<T, const M: usize> VecMin<T, M> {
     where
         T: Clone,
     {
-        if new_len >= M {
-            self.vec.resize(new_len, value);
-            Ok(())
-        } else {
-            Err(ModifyError)
-        }
+        resize(&mut self.vec, M, new_len, value).ok_or(ModifyError)
     }
 
     /// See [`Vec::resize`]. Resizes the vector to `new_len` if `new_len` is greater than or equal to `M`, otherwise resizes the vector to `M`.
@@ -617,12 +967,7 @@ impl<T, const M: usize> VecMin<T, M> {
     where
         F: FnMut() -> T,
     {
-        if new_len >= M {
-            self.vec.resize_with(new_len, generator);
-            Ok(())
-        } else {
-            Err(ModifyError)
-        }
+        resize_with(&mut self.vec, M, new_len, generator).ok_or(ModifyError)
     }
 
     /// See [`Vec::resize_with`]. Resizes the vector to `new_len` if `new_len` is greater than or equal to `M`, otherwise resizes the vector to `M`.
@@ -640,90 +985,1018 @@ impl<T, const M: usize> VecMin<T, M> {
     where
         R: RangeBounds<usize>,
     {
-        let drain_len = slice_range(&range, ..self.vec.len()).len();
-        let final_len = self.vec.len() - drain_len;
-
-        if final_len >= M {
-            Ok(self.vec.drain(range))
-        } else {
-            Err(ModifyError)
-        }
+        drain(&mut self.vec, M, range).ok_or(ModifyError)
     }
 
-    /// See [`Vec::split_off`]. Returns an error if the operation would reduce the length of the vector below `M`.
+    /// See [`Vec::drain`]. Drains all elements after the first `M`.
     #[inline]
-    #[must_use = "this operation may fail"]
-    pub fn split_off(&mut self, at: usize) -> Result<Vec<T>, ModifyError<M>> {
-        if at >= M {
-            Ok(self.vec.split_off(at))
-        } else {
-            Err(ModifyError)
-        }
+    pub fn drain_extra(&mut self) -> vec::Drain<'_, T> {
+        self.vec.drain(M..)
     }
-}
 
-// --- Equality & Ordering ---
-impl<T: PartialEq, const M1: usize, const M2: usize> PartialEq<VecMin<T, M2>> for VecMin<T, M1> {
+    /// See [`Vec::drain`]. Drains the `range` of the elements after the first `M`, where index `0` is the first extra element.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds of the extra elements.
     #[inline]
-    fn eq(&self, other: &VecMin<T, M2>) -> bool {
-        self.as_slice() == other.as_slice()
+    #[track_caller]
+    pub fn drain_extra_range<R>(&mut self, range: R) -> vec::Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        drain_extra_range(&mut self.vec, M, range)
     }
-}
-
-impl<T: Eq, const M: usize> Eq for VecMin<T, M> {}
 
-impl<T: PartialEq, const M: usize> PartialEq<Vec<T>> for VecMin<T, M> {
+    /// See [`Vec::split_off`]. Splits off all elements after the first `M`.
     #[inline]
-    fn eq(&self, other: &Vec<T>) -> bool {
-        self.as_slice() == other.as_slice()
+    pub fn split_off_extra(&mut self) -> Vec<T> {
+        self.vec.split_off(M)
     }
-}
 
-impl<T: PartialEq, const M: usize> PartialEq<VecMin<T, M>> for Vec<T> {
+    /// Removes all elements after the first `M`, returning them and shrinking the capacity of the vector as much as possible.
     #[inline]
-    fn eq(&self, other: &VecMin<T, M>) -> bool {
-        self.as_slice() == other.as_slice()
+    pub fn take_extra(&mut self) -> Vec<T> {
+        take_extra(&mut self.vec, M)
     }
-}
 
-impl<T: PartialOrd, const M1: usize, const M2: usize> PartialOrd<VecMin<T, M2>> for VecMin<T, M1> {
+    /// See [`Vec::split_off`]. Returns an error if the operation would reduce the length of the vector below `M`.
     #[inline]
-    fn partial_cmp(&self, other: &VecMin<T, M2>) -> Option<Ordering> {
-        self.as_slice().partial_cmp(other.as_slice())
+    #[must_use = "this operation may fail"]
+    pub fn split_off(&mut self, at: usize) -> Result<Vec<T>, ModifyError<M>> {
+        split_off(&mut self.vec, M, at).ok_or(ModifyError)
     }
-}
 
-impl<T: Ord, const M: usize> Ord for VecMin<T, M> {
+    /// See [`Vec::split_off`]. Splits off the elements from `at` into a `VecMin` with a minimum of `K`.
+    ///
+    /// Returns an error and leaves the vector unchanged if either half would be shorter than its minimum.
+    ///
+    /// # Panics
+    /// Panics if `at` is greater than the length of the vector.
     #[inline]
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.as_slice().cmp(other.as_slice())
+    #[track_caller]
+    #[must_use = "this operation may fail"]
+    pub fn split_off_min<const K: usize>(
+        &mut self,
+        at: usize,
+    ) -> Result<VecMin<T, K>, SplitError<M, K>> {
+        assert!(
+            at <= self.vec.len(),
+            "`at` split index (is {at}) should be <= len (is {})",
+            self.vec.len()
+        );
+
+        if at < M {
+            Err(SplitError::Head(ModifyError))
+        } else if self.vec.len() - at < K {
+            Err(SplitError::Tail(ModifyError))
+        } else {
+            // Safety: We just checked that the split off elements are at least `K`.
+            Ok(unsafe { VecMin::from_vec_unchecked(self.vec.split_off(at)) })
+        }
     }
-}
 
-impl<T: PartialOrd, const M: usize> PartialOrd<Vec<T>> for VecMin<T, M> {
+    /// Splits the vector into a `VecMin` of exactly its first `M` elements and a `Vec` of the remaining elements.
     #[inline]
-    fn partial_cmp(&self, other: &Vec<T>) -> Option<Ordering> {
-        self.as_slice().partial_cmp(other.as_slice())
+    pub fn partition_at_min(mut self) -> (Self, Vec<T>) {
+        let extra = self.vec.split_off(M);
+        (self, extra)
     }
-}
 
-impl<T: PartialOrd, const M: usize> PartialOrd<VecMin<T, M>> for Vec<T> {
+    /// Splits the vector into a `VecMin` of exactly its first `A` elements and a `VecMin` with a minimum of `B` of the
+    /// remaining elements, where `A + B` is checked at compile time to be at most `M`.
     #[inline]
-    fn partial_cmp(&self, other: &VecMin<T, M>) -> Option<Ordering> {
-        self.as_slice().partial_cmp(other.as_slice())
-    }
-}
+    pub fn split_into<const A: usize, const B: usize>(self) -> (VecMin<T, A>, VecMin<T, B>) {
+        let () = AssertSum::<A, B, M>::SPLIT;
 
-#[cfg(feature = "serde")]
-mod __serde {
-    use serde::de::Error;
-    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+        let mut head = self.vec;
+        let tail = head.split_off(A);
 
-    use super::*;
+        // Safety: The length was at least `M`, which is at least `A + B`.
+        unsafe {
+            (
+                VecMin::from_vec_unchecked(head),
+                VecMin::from_vec_unchecked(tail),
+            )
+        }
+    }
 
-    impl<T: Serialize, const M: usize> Serialize for VecMin<T, M> {
-        #[inline]
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    /// See [`Vec::extract_if`]. Stops extracting once the length of the vector would drop below `M`, leaving the remaining elements in place.
+    ///
+    /// Use [`ExtractIf::is_stopped`] to check whether extraction stopped early because of the minimum.
+    ///
+    /// Runs in linear time. Elements extracted before `M` elements have been kept at the front of the vector are
+    /// buffered until the `M`-th kept element is found, so that a leaked iterator never leaves the vector below `M`.
+    pub fn extract_if<F, R>(&mut self, range: R, filter: F) -> ExtractIf<'_, T, F, M>
+    where
+        F: FnMut(&mut T) -> bool,
+        R: RangeBounds<usize>,
+    {
+        ExtractIf::new(&mut self.vec, range, filter)
+    }
+
+    /// See [`Vec::splice`]. Returns an error if the replacement would reduce the length of the vector below `M`.
+    ///
+    /// Unlike [`Vec::splice`] the replacement is collected before the vector is modified and the removed elements are
+    /// returned in a `Vec`, so the vector can never be left below `M` by a leaked iterator or a panicking
+    /// `replace_with`. If an error is returned the vector is unchanged and the collected elements are dropped.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Result<Vec<T>, ModifyError<M>>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        splice(&mut self.vec, M, range, replace_with).ok_or(ModifyError)
+    }
+}
+
+// The length decreasing operations of `VecMin` for a minimum length `min` known at runtime, which are shared with
+// `DynVecMin`. Fallible operations return `None` and leave the vector unchanged if they would leave fewer than `min`
+// elements.
+
+#[inline]
+pub(crate) fn pop_to_min<T>(vec: &mut Vec<T>, min: usize) -> Option<T> {
+    if vec.len() > min { vec.pop() } else { None }
+}
+
+#[inline]
+pub(crate) fn pop_to_min_if<T>(
+    vec: &mut Vec<T>,
+    min: usize,
+    pred: impl FnOnce(&mut T) -> bool,
+) -> Option<T> {
+    if vec.len() > min {
+        vec.pop_if(pred)
+    } else {
+        None
+    }
+}
+
+#[inline]
+pub(crate) fn remove<T>(vec: &mut Vec<T>, min: usize, index: usize) -> Option<T> {
+    (vec.len() > min).then(|| vec.remove(index))
+}
+
+#[inline]
+pub(crate) fn swap_remove<T>(vec: &mut Vec<T>, min: usize, index: usize) -> Option<T> {
+    (vec.len() > min).then(|| vec.swap_remove(index))
+}
+
+#[inline]
+pub(crate) fn truncate<T>(vec: &mut Vec<T>, min: usize, len: usize) -> Option<()> {
+    (len >= min).then(|| vec.truncate(len))
+}
+
+#[inline]
+pub(crate) fn resize<T: Clone>(
+    vec: &mut Vec<T>,
+    min: usize,
+    new_len: usize,
+    value: T,
+) -> Option<()> {
+    (new_len >= min).then(|| vec.resize(new_len, value))
+}
+
+#[inline]
+pub(crate) fn resize_with<T>(
+    vec: &mut Vec<T>,
+    min: usize,
+    new_len: usize,
+    generator: impl FnMut() -> T,
+) -> Option<()> {
+    (new_len >= min).then(|| vec.resize_with(new_len, generator))
+}
+
+#[inline]
+pub(crate) fn drain<T, R>(vec: &mut Vec<T>, min: usize, range: R) -> Option<vec::Drain<'_, T>>
+where
+    R: RangeBounds<usize>,
+{
+    let drain_len = slice_range(&range, ..vec.len()).len();
+    (vec.len() - drain_len >= min).then(|| vec.drain(range))
+}
+
+#[inline]
+#[track_caller]
+pub(crate) fn drain_extra_range<T, R>(vec: &mut Vec<T>, min: usize, range: R) -> vec::Drain<'_, T>
+where
+    R: RangeBounds<usize>,
+{
+    let range = slice_range(&range, ..vec.len() - min);
+    vec.drain(range.start + min..range.end + min)
+}
+
+#[inline]
+pub(crate) fn take_extra<T>(vec: &mut Vec<T>, min: usize) -> Vec<T> {
+    let extra = vec.split_off(min);
+    vec.shrink_to_fit();
+    extra
+}
+
+#[inline]
+pub(crate) fn split_off<T>(vec: &mut Vec<T>, min: usize, at: usize) -> Option<Vec<T>> {
+    (at >= min).then(|| vec.split_off(at))
+}
+
+/// The implementation of [`VecMin::splice`] for a minimum length `min` known at runtime.
+pub(crate) fn splice<T, R, I>(
+    vec: &mut Vec<T>,
+    min: usize,
+    range: R,
+    replace_with: I,
+) -> Option<Vec<T>>
+where
+    R: RangeBounds<usize>,
+    I: IntoIterator<Item = T>,
+{
+    let range = slice_range(&range, ..vec.len());
+    let required = min.saturating_sub(vec.len() - range.len());
+
+    let replace_with = replace_with.into_iter();
+    let (_, high) = replace_with.size_hint();
+    if high.is_some_and(|high| high < required) {
+        return None;
+    }
+
+    let replace_with: Vec<T> = replace_with.collect();
+    if replace_with.len() < required {
+        return None;
+    }
+
+    Some(vec.splice(range, replace_with).collect())
+}
+
+/// An iterator which uses a closure to determine if an element should be removed from a [`VecMin`].
+///
+/// Created by [`VecMin::extract_if`]. Extraction stops once the vector reaches its minimum length `M`.
+pub struct ExtractIf<'a, T, F, const M: usize> {
+    raw: RawExtractIf<'a, T, F>,
+}
+
+impl<'a, T, F, const M: usize> ExtractIf<'a, T, F, M> {
+    fn new<R: RangeBounds<usize>>(vec: &'a mut Vec<T>, range: R, pred: F) -> Self {
+        Self {
+            raw: RawExtractIf::new(vec, M, range, pred),
+        }
+    }
+
+    /// Returns `true` if extraction stopped because the vector reached its minimum length before the whole range was visited.
+    #[inline]
+    pub fn is_stopped(&self) -> bool {
+        self.raw.is_stopped()
+    }
+}
+
+impl<T, F, const M: usize> Iterator for ExtractIf<'_, T, F, M>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.raw.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.raw.size_hint()
+    }
+}
+
+impl<T: Debug, F, const M: usize> Debug for ExtractIf<'_, T, F, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ExtractIf")
+            .field(&self.raw.remaining())
+            .finish()
+    }
+}
+
+/// The implementation of [`ExtractIf`] for a minimum length `min` known at runtime.
+pub(crate) struct RawExtractIf<'a, T, F> {
+    vec: &'a mut Vec<T>,
+    min: usize,
+    /// The index of the next element to visit.
+    idx: usize,
+    /// The number of elements extracted while compacting.
+    del: usize,
+    /// The end of the range to visit.
+    end: usize,
+    /// The length of the vector including the elements not yet compacted.
+    old_len: usize,
+    /// Elements extracted before `min` elements were kept, which are yielded first.
+    buf: vec::IntoIter<T>,
+    pred: F,
+}
+
+impl<'a, T, F> RawExtractIf<'a, T, F> {
+    pub(crate) fn new<R: RangeBounds<usize>>(
+        vec: &'a mut Vec<T>,
+        min: usize,
+        range: R,
+        pred: F,
+    ) -> Self {
+        let range = slice_range(&range, ..vec.len());
+        let old_len = vec.len();
+
+        Self {
+            vec,
+            min,
+            idx: range.start,
+            del: 0,
+            end: range.end,
+            old_len,
+            buf: Vec::new().into_iter(),
+            pred,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn is_stopped(&self) -> bool {
+        self.old_len - self.del == self.min && self.idx < self.end
+    }
+
+    #[inline]
+    pub(crate) fn remaining(&self) -> &[T] {
+        // Safety: Elements from `idx` to `end` are initialized.
+        unsafe { slice::from_raw_parts(self.vec.as_ptr().add(self.idx), self.end - self.idx) }
+    }
+
+    /// Extracts the element at `idx` while fewer than `min` elements are kept in front of it, scanning ahead until
+    /// `min` elements are kept and buffering the extracted elements, so the tail is only moved once.
+    fn extract_prefix(&mut self) -> Option<T>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        /// Puts the buffered elements back into the gap if the predicate panics, so the length never changes while
+        /// there is a gap.
+        struct Gap<T> {
+            ptr: *mut T,
+            /// The index after the gap.
+            idx: usize,
+            buf: Vec<T>,
+        }
+
+        impl<T> Drop for Gap<T> {
+            fn drop(&mut self) {
+                // Safety: The gap is exactly as long as the buffer, and the buffer gives up ownership of its elements.
+                unsafe {
+                    let len = self.buf.len();
+                    ptr::copy_nonoverlapping(self.buf.as_ptr(), self.ptr.add(self.idx - len), len);
+                    self.buf.set_len(0);
+                }
+            }
+        }
+
+        let ptr = self.vec.as_mut_ptr();
+        let mut gap = Gap {
+            ptr,
+            idx: self.idx,
+            buf: Vec::new(),
+        };
+
+        loop {
+            // Reserve before moving the element out, so a failed reservation leaves no element in both places.
+            gap.buf.reserve(1);
+            // Safety: The element at `idx` is initialized and was selected, the gap grows by one to cover it.
+            unsafe { gap.buf.push(ptr::read(ptr.add(gap.idx))) };
+            gap.idx += 1;
+
+            loop {
+                let kept = gap.idx - gap.buf.len();
+                if gap.idx == self.end
+                    || kept == self.min
+                    || self.old_len - gap.buf.len() == self.min
+                {
+                    let del = gap.buf.len();
+                    let buf = mem::take(&mut gap.buf);
+                    drop(gap);
+
+                    // Safety: Closes the gap by moving the remaining elements after the kept ones, the length stays
+                    // at least `min` since at most `old_len - min` elements were extracted.
+                    unsafe {
+                        ptr::copy(
+                            ptr.add(kept + del),
+                            ptr.add(kept),
+                            self.old_len - kept - del,
+                        );
+                        self.vec.set_len(self.old_len - del);
+                    }
+                    self.idx = kept;
+                    self.end -= del;
+                    self.old_len -= del;
+                    self.buf = buf.into_iter();
+                    return self.buf.next();
+                }
+
+                // Safety: Elements from `idx` to `old_len` are initialized.
+                let cur = unsafe { &mut *ptr.add(gap.idx) };
+                if (self.pred)(cur) {
+                    break;
+                }
+                // Safety: `kept` is the start of the gap, which is not empty.
+                unsafe { ptr::copy_nonoverlapping(cur, ptr.add(kept), 1) };
+                gap.idx += 1;
+            }
+        }
+    }
+}
+
+impl<T, F> Iterator for RawExtractIf<'_, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        // The vector never has a length below `min` or uninitialized elements within its length, so leaking the
+        // iterator only leaks elements. Elements are compacted like `Vec::retain` once at least `min` elements are kept
+        // in front of the gap, before that matching elements are buffered by `extract_prefix`.
+        if let Some(item) = self.buf.next() {
+            return Some(item);
+        }
+
+        while self.idx < self.end && self.old_len - self.del > self.min {
+            let ptr = self.vec.as_mut_ptr();
+            // Safety: Elements from `idx` to `old_len` are initialized and not within the length of the vector
+            // while there is a gap.
+            let cur = unsafe { &mut *ptr.add(self.idx) };
+            let extract = (self.pred)(cur);
+            let kept = self.idx - self.del;
+
+            if self.del == 0 && kept < self.min {
+                if extract {
+                    return self.extract_prefix();
+                }
+                self.idx += 1;
+            } else if extract {
+                // Safety: The length is reduced to the compacted elements, which are at least `min`, before the
+                // element is moved out, leaving a gap at `idx`.
+                unsafe {
+                    self.vec.set_len(kept);
+                    let item = ptr::read(cur);
+                    self.idx += 1;
+                    self.del += 1;
+                    return Some(item);
+                }
+            } else {
+                // Safety: `kept` is within the gap or equal to `idx`, and the compacted elements grow by one.
+                unsafe {
+                    if self.del > 0 {
+                        ptr::copy_nonoverlapping(ptr.add(self.idx), ptr.add(kept), 1);
+                        self.vec.set_len(kept + 1);
+                    }
+                    self.idx += 1;
+                }
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.buf.len();
+        let extractable = (self.old_len - self.del - self.min).min(self.end - self.idx);
+        (buffered, Some(buffered + extractable))
+    }
+}
+
+impl<T, F> Drop for RawExtractIf<'_, T, F> {
+    fn drop(&mut self) {
+        if self.del > 0 {
+            // Safety: Closes the gap by moving the remaining elements after the compacted ones.
+            unsafe {
+                let ptr = self.vec.as_mut_ptr();
+                let kept = self.idx - self.del;
+                ptr::copy(ptr.add(self.idx), ptr.add(kept), self.old_len - self.idx);
+                self.vec.set_len(self.old_len - self.del);
+            }
+        }
+    }
+}
+
+// --- Transpose ---
+impl<T, const M: usize> VecMin<Option<T>, M> {
+    /// Transposes a `VecMin` of `Option`s into an `Option` of a `VecMin`, returning `None` if any element is `None`.
+    #[inline]
+    pub fn transpose(self) -> Option<VecMin<T, M>> {
+        let vec = self.vec.into_iter().collect::<Option<Vec<T>>>()?;
+
+        // Safety: Every element was mapped, so the length is unchanged.
+        Some(unsafe { VecMin::from_vec_unchecked(vec) })
+    }
+}
+
+impl<T, E, const M: usize> VecMin<Result<T, E>, M> {
+    /// Transposes a `VecMin` of `Result`s into a `Result` of a `VecMin`, returning the first error if any.
+    #[inline]
+    pub fn transpose(self) -> Result<VecMin<T, M>, E> {
+        let vec = self.vec.into_iter().collect::<Result<Vec<T>, E>>()?;
+
+        // Safety: Every element was mapped, so the length is unchanged.
+        Ok(unsafe { VecMin::from_vec_unchecked(vec) })
+    }
+
+    /// Separates the successes from the failures, returning an error if there are fewer than `M` successes.
+    #[inline]
+    pub fn sequence_partial(self) -> (Result<VecMin<T, M>, ConstructError<T, M>>, Vec<E>) {
+        let mut oks = Vec::with_capacity(self.vec.len());
+        let mut errs = Vec::new();
+
+        for item in self.vec {
+            match item {
+                Ok(ok) => oks.push(ok),
+                Err(err) => errs.push(err),
+            }
+        }
+
+        (VecMin::try_from_vec(oks), errs)
+    }
+}
+
+impl<T, const M: usize> VecMin<T, M> {
+    /// Maps every element with the fallible `f`, returning all errors instead of stopping at the first one.
+    pub fn try_map_all<U, E>(
+        self,
+        mut f: impl FnMut(T) -> Result<U, E>,
+    ) -> Result<VecMin<U, M>, VecOne<E>> {
+        let mut oks = Vec::with_capacity(self.vec.len());
+        let mut errs = Vec::new();
+
+        for item in self.vec {
+            match f(item) {
+                Ok(ok) if errs.is_empty() => oks.push(ok),
+                Ok(_) => {}
+                Err(err) => errs.push(err),
+            }
+        }
+
+        if errs.is_empty() {
+            // Safety: Every element was mapped, so the length is unchanged.
+            Ok(unsafe { VecMin::from_vec_unchecked(oks) })
+        } else {
+            // Safety: We just checked that there is at least one error.
+            Err(unsafe { VecOne::from_vec_unchecked(errs) })
+        }
+    }
+}
+
+// --- Zip ---
+/// Two vectors of different lengths returned as an error by [`VecMin::zip`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZipError<T, U, const M: usize>(pub VecMin<T, M>, pub VecMin<U, M>);
+
+impl<T, U, const M: usize> Display for ZipError<T, U, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot zip vectors of different lengths {} and {}",
+            self.0.len(),
+            self.1.len()
+        )
+    }
+}
+
+impl<T: Debug, U: Debug, const M: usize> Error for ZipError<T, U, M> {}
+
+impl<T, const M: usize> VecMin<T, M> {
+    /// Zips two vectors into a vector of pairs, returning both vectors as an error if their lengths differ.
+    #[inline]
+    pub fn zip<U>(self, other: VecMin<U, M>) -> Result<VecMin<(T, U), M>, ZipError<T, U, M>> {
+        if self.vec.len() == other.vec.len() {
+            Ok(self.zip_shortest(other))
+        } else {
+            Err(ZipError(self, other))
+        }
+    }
+
+    /// Zips two vectors into a vector of pairs, truncating the longer vector to the length of the shorter one.
+    #[inline]
+    pub fn zip_shortest<U>(self, other: VecMin<U, M>) -> VecMin<(T, U), M> {
+        let vec = self.vec.into_iter().zip(other.vec).collect();
+
+        // Safety: Both vectors have at least `M` elements, so the shorter one does too.
+        unsafe { VecMin::from_vec_unchecked(vec) }
+    }
+}
+
+impl<A, B, const M: usize> VecMin<(A, B), M> {
+    /// Unzips a vector of pairs into a pair of vectors.
+    #[inline]
+    pub fn unzip(self) -> (VecMin<A, M>, VecMin<B, M>) {
+        self.multiunzip()
+    }
+}
+
+macro_rules! multiunzip {
+    ($($ty:ident $idx:tt $var:ident),+) => {
+        impl<$($ty),+, const M: usize> VecMin<($($ty,)+), M> {
+            /// Unzips a vector of tuples into a tuple of vectors.
+            #[inline]
+            pub fn multiunzip(self) -> ($(VecMin<$ty, M>,)+) {
+                let len = self.vec.len();
+                $(let mut $var = Vec::with_capacity(len);)+
+
+                for tuple in self.vec {
+                    $($var.push(tuple.$idx);)+
+                }
+
+                // Safety: Every vector received one element per tuple, so the length is unchanged.
+                unsafe { ($(VecMin::from_vec_unchecked($var),)+) }
+            }
+        }
+    };
+}
+
+multiunzip!(A 0 a, B 1 b);
+multiunzip!(A 0 a, B 1 b, C 2 c);
+multiunzip!(A 0 a, B 1 b, C 2 c, D 3 d);
+multiunzip!(A 0 a, B 1 b, C 2 c, D 3 d, E 4 e);
+multiunzip!(A 0 a, B 1 b, C 2 c, D 3 d, E 4 e, F 5 f);
+
+// --- Flatten ---
+impl<T, const A: usize, const B: usize> VecMin<VecMin<T, A>, B> {
+    /// Flattens the nested vectors into a `VecMin` with a minimum of `N`, which is checked at compile time to be at most `A * B`.
+    #[inline]
+    pub fn flatten<const N: usize>(self) -> VecMin<T, N> {
+        let () = AssertProduct::<A, B, N>::FLATTEN;
+
+        let mut vec = Vec::with_capacity(self.vec.iter().map(|inner| inner.len()).sum());
+        for mut inner in self.vec {
+            vec.append(&mut inner.vec);
+        }
+
+        // Safety: There are at least `B` vectors of at least `A` elements, which is at least `N`.
+        unsafe { VecMin::from_vec_unchecked(vec) }
+    }
+
+    /// See [`slice::concat`], which stays reachable through `Deref`. Returns a `VecMin` with a minimum of `N`, which is
+    /// checked at compile time to be at most `A * B`.
+    #[inline]
+    pub fn concat_min<const N: usize>(&self) -> VecMin<T, N>
+    where
+        T: Clone,
+    {
+        let () = AssertProduct::<A, B, N>::FLATTEN;
+
+        // Safety: There are at least `B` slices of at least `A` elements, which is at least `N`.
+        unsafe { VecMin::from_vec_unchecked(self.vec.concat()) }
+    }
+
+    /// See [`slice::join`], which stays reachable through `Deref`. Returns a `VecMin` with a minimum of `N`, which is
+    /// checked at compile time to be at most `A * B`.
+    #[inline]
+    pub fn join_min<const N: usize>(&self, separator: &T) -> VecMin<T, N>
+    where
+        T: Clone,
+    {
+        let () = AssertProduct::<A, B, N>::FLATTEN;
+
+        // Safety: There are at least `B` slices of at least `A` elements, which is at least `N`.
+        unsafe { VecMin::from_vec_unchecked(self.vec.join(separator)) }
+    }
+}
+
+impl<T, const A: usize, const B: usize> VecMin<[T; A], B> {
+    /// See [`slice::concat`], which stays reachable through `Deref`. Returns a `VecMin` with a minimum of `N`, which is
+    /// checked at compile time to be at most `A * B`.
+    #[inline]
+    pub fn concat_min<const N: usize>(&self) -> VecMin<T, N>
+    where
+        T: Clone,
+    {
+        let () = AssertProduct::<A, B, N>::FLATTEN;
+
+        // Safety: There are at least `B` arrays of `A` elements, which is at least `N`.
+        unsafe { VecMin::from_vec_unchecked(self.vec.concat()) }
+    }
+
+    /// See [`slice::join`], which stays reachable through `Deref`. Returns a `VecMin` with a minimum of `N`, which is
+    /// checked at compile time to be at most `A * B`.
+    #[inline]
+    pub fn join_min<const N: usize>(&self, separator: &T) -> VecMin<T, N>
+    where
+        T: Clone,
+    {
+        let () = AssertProduct::<A, B, N>::FLATTEN;
+
+        // Safety: There are at least `B` arrays of `A` elements, which is at least `N`.
+        unsafe { VecMin::from_vec_unchecked(self.vec.join(separator)) }
+    }
+}
+
+impl<T, const M: usize> VecMin<T, M> {
+    /// See [`Iterator::flat_map`]. Maps every element to a `VecMin` with a minimum of `K` and flattens the results into a
+    /// `VecMin` with a minimum of `N`, which is checked at compile time to be at most `M * K`.
+    #[inline]
+    pub fn flat_map<U, const K: usize, const N: usize>(
+        self,
+        f: impl FnMut(T) -> VecMin<U, K>,
+    ) -> VecMin<U, N> {
+        let () = AssertProduct::<M, K, N>::FLATTEN;
+
+        let mut vec = Vec::with_capacity(self.vec.len() * K);
+        for mut inner in self.vec.into_iter().map(f) {
+            vec.append(&mut inner.vec);
+        }
+
+        // Safety: There are at least `M` vectors of at least `K` elements, which is at least `N`.
+        unsafe { VecMin::from_vec_unchecked(vec) }
+    }
+}
+
+// --- Grouping ---
+impl<T, const M: usize> VecMin<T, M> {
+    /// See [`slice::chunk_by`]. Groups consecutive runs of elements for which `pred` returns `true` on each adjacent
+    /// pair into owned groups, requiring `M` to be at least 1 at compile time.
+    ///
+    /// Named so that [`slice::chunk_by`] stays reachable through `Deref`.
+    pub fn into_chunk_by(self, mut pred: impl FnMut(&T, &T) -> bool) -> VecOne<VecOne<T>> {
+        let () = AssertMin::<M>::NON_EMPTY;
+
+        let mut groups = Vec::new();
+        let mut iter = self.vec.into_iter();
+        let Some(first) = iter.next() else {
+            unreachable!("a `VecMin` with a minimum of at least 1 is not empty")
+        };
+
+        let mut group = VecOne::new(first);
+        for item in iter {
+            let [last] = group.last_chunk();
+            if pred(last, &item) {
+                group.push(item);
+            } else {
+                groups.push(mem::replace(&mut group, VecOne::new(item)));
+            }
+        }
+        groups.push(group);
+
+        // Safety: The last group is always pushed.
+        unsafe { VecOne::from_vec_unchecked(groups) }
+    }
+
+    /// Groups consecutive runs of elements with equal keys, see [`VecMin::into_chunk_by`].
+    #[inline]
+    pub fn into_chunk_by_key<K: PartialEq>(self, mut f: impl FnMut(&T) -> K) -> VecOne<VecOne<T>> {
+        self.into_chunk_by(|a, b| f(a) == f(b))
+    }
+
+    /// See [`slice::chunk_by`]. Borrowed version of [`VecMin::into_chunk_by`], every group is a non-empty slice.
+    #[inline]
+    pub fn chunk_by_ref(&self, pred: impl FnMut(&T, &T) -> bool) -> VecOne<&[T]> {
+        let () = AssertMin::<M>::NON_EMPTY;
+
+        // Safety: A non-empty slice has at least one chunk.
+        unsafe { VecOne::from_vec_unchecked(self.vec.chunk_by(pred).collect()) }
+    }
+
+    /// See [`slice::split`]. Splits the vector on elements matching `pred`, which are removed, skipping empty groups.
+    pub fn split_on(self, mut pred: impl FnMut(&T) -> bool) -> Vec<VecOne<T>> {
+        let mut groups = Vec::new();
+        let mut group = Vec::new();
+
+        for item in self.vec {
+            if pred(&item) {
+                if let Ok(group) = VecOne::try_from_vec(mem::take(&mut group)) {
+                    groups.push(group);
+                }
+            } else {
+                group.push(item);
+            }
+        }
+        if let Ok(group) = VecOne::try_from_vec(group) {
+            groups.push(group);
+        }
+
+        groups
+    }
+
+    /// See [`slice::split_inclusive`]. Splits the vector after elements matching `pred`, which end their group,
+    /// requiring `M` to be at least 1 at compile time.
+    pub fn split_on_inclusive(self, mut pred: impl FnMut(&T) -> bool) -> VecOne<VecOne<T>> {
+        let () = AssertMin::<M>::NON_EMPTY;
+
+        let mut groups = Vec::new();
+        let mut group = Vec::new();
+
+        for item in self.vec {
+            let end = pred(&item);
+            group.push(item);
+
+            if end {
+                // Safety: The matching element was just pushed.
+                groups.push(unsafe { VecOne::from_vec_unchecked(mem::take(&mut group)) });
+            }
+        }
+        if !group.is_empty() {
+            // Safety: We just checked that the group is not empty.
+            groups.push(unsafe { VecOne::from_vec_unchecked(group) });
+        }
+
+        // Safety: The vector is not empty, so at least one group was pushed.
+        unsafe { VecOne::from_vec_unchecked(groups) }
+    }
+}
+
+impl<T, const M: usize> VecMin<VecOne<T>, M> {
+    /// Flattens the groups back into a single `VecMin`, reversing [`VecMin::into_chunk_by`].
+    #[inline]
+    pub fn ungroup(self) -> VecMin<T, M> {
+        self.flatten()
+    }
+}
+
+// --- Scoped Access ---
+impl<T, const M: usize> VecMin<T, M> {
+    /// Returns a guard with full access to a copy of the inner `Vec`.
+    ///
+    /// The copy replaces the contents of the vector on [`VecMinGuard::commit`] if it satisfies the minimum length,
+    /// otherwise the vector is left unchanged.
+    ///
+    /// Cloning the vector takes linear time and memory, see [`VecMin::with_vec_or_repair`] to mutate in place.
+    #[inline]
+    pub fn guard(&mut self) -> VecMinGuard<'_, T, M>
+    where
+        T: Clone,
+    {
+        let vec = self.vec.clone();
+        VecMinGuard { vec_min: self, vec }
+    }
+
+    /// Calls `f` with full access to the inner `Vec`, returning an error and restoring the previous contents if the
+    /// length was reduced below `M`.
+    ///
+    /// `f` is given a clone of the vector, so every call takes linear time and memory even if it succeeds. Use
+    /// [`VecMin::with_vec_or_repair`] to mutate the vector in place when it can be repaired instead of restored.
+    #[inline]
+    pub fn with_vec<R>(&mut self, f: impl FnOnce(&mut Vec<T>) -> R) -> Result<R, ModifyError<M>>
+    where
+        T: Clone,
+    {
+        let mut guard = self.guard();
+        let result = f(&mut guard);
+        guard.commit().map(|()| result)
+    }
+
+    /// Calls `f` with full access to the inner `Vec`, returning an error and filling the vector to `M` with `repair` if
+    /// the length was reduced below `M`.
+    ///
+    /// The vector is also repaired if `f` panics. If `repair` panics while the vector is below `M` the process is
+    /// aborted, since the vector could not be restored to a valid state.
+    pub fn with_vec_or_repair<R>(
+        &mut self,
+        f: impl FnOnce(&mut Vec<T>) -> R,
+        repair: impl FnMut() -> T,
+    ) -> Result<R, ModifyError<M>> {
+        with_vec_or_repair(&mut self.vec, M, f, repair).ok_or(ModifyError)
+    }
+}
+
+/// The implementation of [`VecMin::with_vec_or_repair`] for a minimum length `min` known at runtime.
+pub(crate) fn with_vec_or_repair<T, R>(
+    vec: &mut Vec<T>,
+    min: usize,
+    f: impl FnOnce(&mut Vec<T>) -> R,
+    repair: impl FnMut() -> T,
+) -> Option<R> {
+    struct Repair<'a, T, F: FnMut() -> T> {
+        vec: &'a mut Vec<T>,
+        min: usize,
+        repair: F,
+    }
+
+    impl<T, F: FnMut() -> T> Drop for Repair<'_, T, F> {
+        fn drop(&mut self) {
+            if self.vec.len() < self.min {
+                // Panicking while `bomb` is dropped during unwinding aborts the process.
+                let bomb = AbortOnUnwind;
+                self.vec.resize_with(self.min, &mut self.repair);
+                mem::forget(bomb);
+            }
+        }
+    }
+
+    let guard = Repair { vec, min, repair };
+    let result = f(guard.vec);
+
+    (guard.vec.len() >= min).then_some(result)
+}
+
+/// Panics when dropped, which aborts the process if it is dropped while unwinding from another panic.
+///
+/// Forgotten with [`mem::forget`] once the code it guards has completed.
+struct AbortOnUnwind;
+
+impl Drop for AbortOnUnwind {
+    fn drop(&mut self) {
+        panic!("`repair` panicked while the vector was below its minimum length");
+    }
+}
+
+/// A guard with full access to a copy of the inner `Vec` of a [`VecMin`].
+///
+/// Created by [`VecMin::guard`]. Changes are only applied to the `VecMin` by [`VecMinGuard::commit`].
+#[derive(Debug)]
+pub struct VecMinGuard<'a, T, const M: usize> {
+    vec_min: &'a mut VecMin<T, M>,
+    vec: Vec<T>,
+}
+
+impl<T, const M: usize> VecMinGuard<'_, T, M> {
+    /// Replaces the contents of the `VecMin` with the guarded `Vec`, returning an error and discarding the changes if
+    /// the length is less than `M`.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn commit(self) -> Result<(), ModifyError<M>> {
+        commit(&mut self.vec_min.vec, M, self.vec).ok_or(ModifyError)
+    }
+}
+
+/// The implementation of [`VecMinGuard::commit`] for a minimum length `min` known at runtime.
+#[inline]
+pub(crate) fn commit<T>(vec: &mut Vec<T>, min: usize, guarded: Vec<T>) -> Option<()> {
+    (guarded.len() >= min).then(|| *vec = guarded)
+}
+
+impl<T, const M: usize> Deref for VecMinGuard<'_, T, M> {
+    type Target = Vec<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+impl<T, const M: usize> DerefMut for VecMinGuard<'_, T, M> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.vec
+    }
+}
+
+// --- Equality & Ordering ---
+impl<T: PartialEq, const M1: usize, const M2: usize> PartialEq<VecMin<T, M2>> for VecMin<T, M1> {
+    #[inline]
+    fn eq(&self, other: &VecMin<T, M2>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const M: usize> Eq for VecMin<T, M> {}
+
+impl<T: PartialEq, const M: usize> PartialEq<Vec<T>> for VecMin<T, M> {
+    #[inline]
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, const M: usize> PartialEq<VecMin<T, M>> for Vec<T> {
+    #[inline]
+    fn eq(&self, other: &VecMin<T, M>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialOrd, const M1: usize, const M2: usize> PartialOrd<VecMin<T, M2>> for VecMin<T, M1> {
+    #[inline]
+    fn partial_cmp(&self, other: &VecMin<T, M2>) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, const M: usize> Ord for VecMin<T, M> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: PartialOrd, const M: usize> PartialOrd<Vec<T>> for VecMin<T, M> {
+    #[inline]
+    fn partial_cmp(&self, other: &Vec<T>) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: PartialOrd, const M: usize> PartialOrd<VecMin<T, M>> for Vec<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &VecMin<T, M>) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+#[cfg(feature = "serde")]
+mod __serde {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    impl<T: Serialize, const M: usize> Serialize for VecMin<T, M> {
+        #[inline]
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
             S: Serializer,
         {
@@ -742,3 +2015,346 @@ mod __serde {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::mem;
+
+    use super::ZipError;
+    use crate::{ModifyError, SplitError, VecMin, VecOne, vecmin, vecone};
+
+    #[test]
+    fn extract_if() {
+        let mut v = vecmin![2; [1, 2, 3, 4, 5, 6]];
+
+        {
+            let mut iter = v.extract_if(.., |x| *x % 2 == 0);
+            assert_eq!(iter.next(), Some(2));
+            assert!(!iter.is_stopped());
+        }
+        assert_eq!(v, vec![1, 3, 4, 5, 6]);
+
+        {
+            let mut iter = v.extract_if(1.., |_| true);
+            assert_eq!(iter.by_ref().collect::<Vec<_>>(), [3, 4, 5]);
+            assert!(iter.is_stopped());
+        }
+        assert_eq!(v, vec![1, 6]);
+
+        let mut v = VecMin::<_, 2>::try_new((0..10).collect::<Vec<_>>()).unwrap();
+        let mut iter = v.extract_if(.., |x| *x % 3 != 0);
+        assert_eq!(iter.by_ref().take(4).collect::<Vec<_>>(), [1, 2, 4, 5]);
+        mem::forget(iter);
+        assert_eq!(v, vec![0, 3]);
+
+        let mut v = VecMin::<_, 2>::try_new((0..10).collect::<Vec<_>>()).unwrap();
+        assert!(v.extract_if(.., |x| *x % 3 != 0).eq([1, 2, 4, 5, 7, 8]));
+        assert_eq!(v, vec![0, 3, 6, 9]);
+    }
+
+    #[test]
+    fn extract_if_prefix() {
+        // Quadratic in a large matching prefix if the prefix is not buffered.
+        let mut v = VecOne::try_new((0..200_000).collect::<Vec<_>>()).unwrap();
+        let mut iter = v.extract_if(.., |x| *x < 199_990);
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.size_hint(), (199_989, Some(199_998)));
+        mem::forget(iter);
+        assert_eq!(v, (199_990..200_000).collect::<Vec<_>>());
+
+        let mut v = VecOne::try_new((0..200_000).collect::<Vec<_>>()).unwrap();
+        let mut iter = v.extract_if(.., |_| true);
+        assert!(iter.by_ref().eq(0..199_999));
+        assert!(iter.is_stopped());
+        drop(iter);
+        assert_eq!(v, vec![199_999]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn extract_if_prefix_panic() {
+        use std::panic::{AssertUnwindSafe, catch_unwind};
+
+        // The buffered elements are put back into the gap, so the vector keeps its length.
+        let mut v = vecmin![2; [0, 1, 2, 3, 4, 5]];
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            v.extract_if(.., |x| match *x {
+                1 => false,
+                3 => panic!("pred"),
+                _ => true,
+            })
+            .count()
+        }));
+        assert!(result.is_err());
+        assert_eq!(v, vec![1, 0, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn extra() {
+        let mut v = vecmin![2; [1, 2, 3, 4, 5, 6]];
+        assert_eq!(v.extra_slice(), &[3, 4, 5, 6]);
+
+        assert!(v.drain_extra_range(1..3).eq([4, 5]));
+        assert_eq!(v, vec![1, 2, 3, 6]);
+
+        assert_eq!(v.split_off_extra(), [3, 6]);
+        assert!(v.is_minimum());
+        assert!(v.drain_extra().next().is_none());
+    }
+
+    #[test]
+    fn parts() {
+        let v = vecmin![2; [1, 2, 3, 4]];
+        let ptr = v.as_ptr();
+
+        let (min, extra) = v.into_parts();
+        assert_eq!(min, [1, 2]);
+        assert_eq!(extra, [3, 4]);
+        assert_eq!(extra.as_ptr(), ptr);
+
+        let v = VecMin::from_parts(min, extra);
+        assert_eq!(v, vec![1, 2, 3, 4]);
+        assert_eq!(v.into_min_array(), [1, 2]);
+
+        let (head, tail) = vecone![1].into_head_tail();
+        assert_eq!(head, 1);
+        assert!(tail.is_empty());
+    }
+
+    #[test]
+    fn with_vec() {
+        let mut v = vecmin![2; [3, 1, 3, 2]];
+
+        assert_eq!(v.with_vec(|v| v.clear()), Err(ModifyError));
+        assert_eq!(v, vec![3, 1, 3, 2]);
+
+        v.with_vec(|v| {
+            v.sort();
+            v.dedup();
+        })
+        .unwrap();
+        assert_eq!(v, vec![1, 2, 3]);
+
+        assert_eq!(v.with_vec_or_repair(|v| v.pop(), || 0), Ok(Some(3)));
+        assert_eq!(v.with_vec_or_repair(|v| v.clear(), || 0), Err(ModifyError));
+        assert_eq!(v, vec![0, 0]);
+
+        let mut guard = v.guard();
+        guard.clear();
+        assert!(guard.commit().is_err());
+        assert_eq!(v, vec![0, 0]);
+    }
+
+    #[test]
+    #[should_panic = "`repair` panicked while the vector was below its minimum length"]
+    fn abort_on_unwind() {
+        // Dropped outside of unwinding the guard only panics, which is what aborts inside `with_vec_or_repair`.
+        drop(super::AbortOnUnwind);
+    }
+
+    // Aborting can only be observed from another process, so this needs `std` and is limited to unix, where an abort
+    // is reported as a signal. `abort_on_unwind` covers the guard itself on every target.
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    fn with_vec_or_repair_panic() {
+        use std::{env, process};
+
+        // A panicking `repair` can not restore the minimum, so the test is rerun in a child process that must abort.
+        if env::var_os("VECMIN_REPAIR_PANIC").is_some() {
+            let mut v = vecmin![2; [1, 2]];
+            let _ = v.with_vec_or_repair(|v| v.clear(), || panic!("repair"));
+            return;
+        }
+
+        let status = process::Command::new(env::current_exe().unwrap())
+            .args(["--exact", "vec::tests::with_vec_or_repair_panic"])
+            .env("VECMIN_REPAIR_PANIC", "1")
+            .stdout(process::Stdio::null())
+            .stderr(process::Stdio::null())
+            .status()
+            .unwrap();
+        assert_eq!(status.code(), None, "expected an abort, got {status}");
+    }
+
+    #[test]
+    fn windows_chunks() {
+        let v = vecmin![2; [1, 2, 3, 4, 5]];
+
+        assert!(
+            v.array_windows::<2>()
+                .eq([&[1, 2], &[2, 3], &[3, 4], &[4, 5]])
+        );
+        assert!(v.array_chunks::<2>().eq([&[1, 2], &[3, 4]]));
+        assert!(v.pairwise().eq([(&1, &2), (&2, &3), (&3, &4), (&4, &5)]));
+
+        let chunks = v.chunks_min(2);
+        assert_eq!(chunks.first(), Some(&&[1, 2][..]));
+        assert_eq!(chunks.len(), 3);
+    }
+
+    #[test]
+    fn const_access() {
+        let mut v = vecmin![3; [1, 2, 3, 4]];
+
+        assert_eq!(*v.at::<2>(), 3);
+        *v.at_mut::<0>() = 0;
+        assert_eq!(v.first_chunk::<2>(), &[0, 2]);
+        assert_eq!(v.last_chunk::<2>(), &[3, 4]);
+
+        let (extra, min) = v.split_last_min_mut();
+        assert_eq!(extra, &[0]);
+        min.reverse();
+        assert_eq!(v, vec![0, 4, 3, 2]);
+    }
+
+    #[test]
+    fn head_tail() {
+        let mut v = VecOne::from_head_tail(2, vec![3]).cons(1);
+        assert_eq!(v.head(), &1);
+        assert_eq!(v.tail(), &[2, 3]);
+
+        *v.head_mut() = 0;
+        v.tail_mut()[0] = 5;
+        assert_eq!(v.uncons(), (0, vec![5, 3]));
+        assert_eq!(VecOne::new(4).uncons(), (4, vec![]));
+    }
+
+    #[test]
+    fn padded() {
+        let v = VecMin::<_, 4>::from_vec_padded(vec![1], 0);
+        assert_eq!(v, vec![1, 0, 0, 0]);
+        assert_eq!(v.capacity(), 4);
+
+        let v = VecMin::<_, 3>::from_iter_padded(1..=5, || 0);
+        assert_eq!(v, vec![1, 2, 3, 4, 5]);
+
+        assert!(VecMin::<_, 3>::repeat(1, 2).is_err());
+        assert_eq!(VecMin::<_, 3>::from_fn(3, |i| i).unwrap(), vec![0, 1, 2]);
+
+        let v = VecMin::<_, 5>::from_vec_cycled(vec![1, 2]).unwrap();
+        assert_eq!(v, vec![1, 2, 1, 2, 1]);
+        assert!(VecMin::<i32, 5>::from_vec_cycled(vec![]).is_err());
+    }
+
+    #[test]
+    fn array_n() {
+        let v = VecOne::from_array_n([1, 2, 3]);
+        assert_eq!(v, VecOne::from_array_ref_n(&[1, 2, 3]));
+
+        let v = v.into_array_exact::<2>().unwrap_err();
+        assert_eq!(v.into_array_exact::<3>(), Ok([1, 2, 3]));
+    }
+
+    #[test]
+    fn split_min() {
+        let mut v = vecmin![2; [1, 2, 3, 4, 5]];
+
+        assert_eq!(v.split_off_min::<2>(1), Err(SplitError::Head(ModifyError)));
+        assert_eq!(v.split_off_min::<3>(3), Err(SplitError::Tail(ModifyError)));
+        assert_eq!(v.split_off_min::<1>(4), Ok(vecone![5]));
+
+        let (head, tail) = v.clone().split_into::<1, 1>();
+        assert_eq!(head, vec![1]);
+        assert_eq!(tail, vec![2, 3, 4]);
+
+        let (min, extra) = v.partition_at_min();
+        assert_eq!(min, vec![1, 2]);
+        assert_eq!(extra, [3, 4]);
+    }
+
+    #[test]
+    fn transpose() {
+        assert_eq!(vecmin![Some(1), Some(2)].transpose(), Some(vecmin![1, 2]));
+        assert_eq!(vecmin![Some(1), None].transpose(), None);
+
+        let v = VecMin::<Result<i32, i32>, 2>::try_new([Ok(1), Err(2), Ok(3)]).unwrap();
+        assert_eq!(v.clone().transpose(), Err(2));
+
+        let (oks, errs) = v.sequence_partial();
+        assert_eq!(oks.unwrap(), vec![1, 3]);
+        assert_eq!(errs, [2]);
+
+        let v = vecmin![1, 2, 3, 4];
+        let errs = v
+            .clone()
+            .try_map_all(|x| if x % 2 == 0 { Err(x) } else { Ok(x) });
+        assert_eq!(errs, Err(vecone![2, 4]));
+        assert_eq!(v.try_map_all(Ok::<_, ()>), Ok(vecmin![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn zip() {
+        let a = VecMin::<_, 2>::try_new([1, 2, 3]).unwrap();
+        let b = VecMin::<_, 2>::try_new(['a', 'b']).unwrap();
+
+        let ZipError(a, b) = a.zip(b).unwrap_err();
+        let pairs = a.zip_shortest(b);
+        assert_eq!(pairs, vec![(1, 'a'), (2, 'b')]);
+        assert_eq!(pairs.unzip(), (vecmin![1, 2], vecmin!['a', 'b']));
+
+        let (a, b, c) = vecone![(1, 'a', "x")].multiunzip();
+        assert_eq!((a, b, c), (vecone![1], vecone!['a'], vecone!["x"]));
+    }
+
+    #[test]
+    fn flatten() {
+        let v = VecOne::from_array_n([VecOne::from_array_n([1, 2]), VecOne::new(3)]);
+
+        let flat: VecOne<_> = v.clone().flatten();
+        assert_eq!(flat, vec![1, 2, 3]);
+        assert_eq!(v.concat_min::<1>(), vec![1, 2, 3]);
+        assert_eq!(v.join_min::<1>(&0), vec![1, 2, 0, 3]);
+        assert_eq!(v.concat(), [1, 2, 3]);
+
+        let arrays = VecMin::from_array([[1, 2], [3, 4]]);
+        assert_eq!(arrays.concat_min::<4>(), vec![1, 2, 3, 4]);
+        assert_eq!(arrays.join_min::<4>(&0), vec![1, 2, 0, 3, 4]);
+        assert_eq!(arrays.join(&0), [1, 2, 0, 3, 4]);
+
+        let mut v: VecMin<_, 4> = vecmin![1, 2].flat_map(|x| VecMin::from_array([x, x * 10]));
+        assert_eq!(v, vec![1, 10, 2, 20]);
+
+        v.append_vecmin(vecone![5]);
+        assert_eq!(v.len(), 5);
+    }
+
+    #[test]
+    fn grouping() {
+        let v = vecone![1, 1, 2, 3, 3];
+
+        let groups = v.clone().into_chunk_by(|a, b| a == b);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[2], vec![3, 3]);
+        assert_eq!(groups.ungroup(), v);
+
+        let groups = v.clone().into_chunk_by_key(|x| *x % 2);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(v.chunk_by_ref(|a, b| a <= b), vec![&[1, 1, 2, 3, 3][..]]);
+        assert_eq!(v.chunk_by_ref(|a, b| a == b)[1], [2]);
+        assert_eq!(v.chunk_by(|a, b| a == b).count(), 3);
+
+        let groups = vecone![0, 1, 0, 0, 2].split_on(|x| *x == 0);
+        assert_eq!(groups, [vecone![1], vecone![2]]);
+
+        let groups = vecone![1, 0, 2, 0].split_on_inclusive(|x| *x == 0);
+        assert_eq!(groups, vec![vecone![1, 0], vecone![2, 0]]);
+    }
+
+    #[test]
+    fn splice() {
+        let mut v: VecMin<_, 3> = vecmin![3; [1, 2, 3, 4]];
+
+        assert!(v.splice(..3, [9]).is_err());
+        assert!(v.splice(.., (0..5).filter(|x| *x > 3)).is_err());
+        assert_eq!(v, vec![1, 2, 3, 4]);
+
+        let removed = v.splice(..2, (0..5).filter(|x| *x < 1)).unwrap();
+        assert_eq!(removed, [1, 2]);
+        assert_eq!(v, vec![0, 3, 4]);
+
+        assert_eq!(v.splice(1.., [7, 8, 9]), Ok(vec![3, 4]));
+        assert_eq!(v, vec![0, 7, 8, 9]);
+    }
+}
@@ -0,0 +1,268 @@
+//! Implementation of the [`ZipperOne`] non-empty list with a selected element.
+
+use core::ops::{Deref, DerefMut};
+
+use crate::iter::TryFromIterator;
+use crate::vec::ConstructError;
+use crate::{ModifyError, VecOne};
+
+/// How [`ZipperOne::select_next`] and [`ZipperOne::select_prev`] behave when moving past either end.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    /// Moves to the element at the opposite end.
+    Wrap,
+    /// Stays at the first or last element.
+    Clamp,
+}
+
+/// A non-empty list with a selected element that always exists.
+///
+/// Operations that insert or remove elements adjust the selection, so the selected element never dangles.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ZipperOne<T> {
+    vec: VecOne<T>,
+    index: usize,
+}
+
+impl<T> ZipperOne<T> {
+    /// Creates a new `ZipperOne` with the element at `index` selected, returning the vector as an error if `index` is
+    /// out of bounds.
+    #[inline]
+    pub fn new(vec: VecOne<T>, index: usize) -> Result<Self, VecOne<T>> {
+        if index < vec.len() {
+            Ok(Self { vec, index })
+        } else {
+            Err(vec)
+        }
+    }
+
+    /// Creates a new `ZipperOne` containing a single selected element.
+    #[inline]
+    pub fn singleton(element: T) -> Self {
+        Self {
+            vec: VecOne::new(element),
+            index: 0,
+        }
+    }
+
+    /// Returns the vector and the index of the selected element, consuming the zipper.
+    #[inline]
+    pub fn into_parts(self) -> (VecOne<T>, usize) {
+        (self.vec, self.index)
+    }
+
+    /// Returns the vector, consuming the zipper.
+    #[inline]
+    pub fn into_vec_one(self) -> VecOne<T> {
+        self.vec
+    }
+
+    /// Returns a reference to the underlying `VecOne`.
+    #[inline]
+    pub fn as_vec_one(&self) -> &VecOne<T> {
+        &self.vec
+    }
+
+    /// Returns the index of the selected element.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns a reference to the selected element.
+    #[inline]
+    pub fn selected(&self) -> &T {
+        // Safety: The index is always less than the length.
+        unsafe { self.vec.get_unchecked(self.index) }
+    }
+
+    /// Returns a mutable reference to the selected element.
+    #[inline]
+    pub fn selected_mut(&mut self) -> &mut T {
+        // Safety: The index is always less than the length.
+        unsafe { self.vec.get_unchecked_mut(self.index) }
+    }
+
+    /// Selects the element at `index`, returning `None` and keeping the selection if `index` is out of bounds.
+    #[inline]
+    pub fn select(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.vec.len() {
+            self.index = index;
+            Some(self.selected_mut())
+        } else {
+            None
+        }
+    }
+
+    /// Selects the first element.
+    #[inline]
+    pub fn select_first(&mut self) -> &mut T {
+        self.index = 0;
+        self.selected_mut()
+    }
+
+    /// Selects the last element.
+    #[inline]
+    pub fn select_last(&mut self) -> &mut T {
+        self.index = self.vec.len() - 1;
+        self.selected_mut()
+    }
+
+    /// Selects the next element, wrapping to the first or staying at the last element according to `overflow`.
+    #[inline]
+    pub fn select_next(&mut self, overflow: Overflow) -> &mut T {
+        if self.index + 1 < self.vec.len() {
+            self.index += 1;
+        } else if overflow == Overflow::Wrap {
+            self.index = 0;
+        }
+        self.selected_mut()
+    }
+
+    /// Selects the previous element, wrapping to the last or staying at the first element according to `overflow`.
+    #[inline]
+    pub fn select_prev(&mut self, overflow: Overflow) -> &mut T {
+        if self.index > 0 {
+            self.index -= 1;
+        } else if overflow == Overflow::Wrap {
+            self.index = self.vec.len() - 1;
+        }
+        self.selected_mut()
+    }
+
+    /// See [`VecOne::push`], the selection is unchanged.
+    #[inline]
+    pub fn push(&mut self, element: T) {
+        self.vec.push(element);
+    }
+
+    /// See [`VecOne::insert`], the selection keeps pointing at the same element.
+    #[inline]
+    pub fn insert(&mut self, index: usize, element: T) {
+        self.vec.insert(index, element);
+        if index <= self.index {
+            self.index += 1;
+        }
+    }
+
+    /// Inserts an element after the selected element and selects it.
+    #[inline]
+    pub fn insert_selected(&mut self, element: T) {
+        self.index += 1;
+        self.vec.insert(self.index, element);
+    }
+
+    /// See [`VecOne::remove`], the selection keeps pointing at the same element.
+    ///
+    /// If the selected element is removed, the next element is selected, or the previous one if it was the last.
+    /// Returns an error if the operation would leave the zipper empty.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn remove(&mut self, index: usize) -> Result<T, ModifyError<1>> {
+        let element = self.vec.remove(index)?;
+
+        if index < self.index || self.index == self.vec.len() {
+            self.index -= 1;
+        }
+        Ok(element)
+    }
+
+    /// Removes the selected element, see [`ZipperOne::remove`].
+    #[inline]
+    #[must_use = "this operation may fail"]
+    pub fn remove_selected(&mut self) -> Result<T, ModifyError<1>> {
+        self.remove(self.index)
+    }
+
+    /// Maps every element with `f`, keeping the selection.
+    #[inline]
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> ZipperOne<U> {
+        let vec = self.vec.into_iter().map(f).collect();
+
+        ZipperOne {
+            // Safety: Every element was mapped, so the length is unchanged.
+            vec: unsafe { VecOne::from_vec_unchecked(vec) },
+            index: self.index,
+        }
+    }
+}
+
+impl<T> Deref for ZipperOne<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+impl<T> DerefMut for ZipperOne<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.vec
+    }
+}
+
+impl<T> From<VecOne<T>> for ZipperOne<T> {
+    /// Creates a `ZipperOne` with the first element selected.
+    #[inline]
+    fn from(vec: VecOne<T>) -> Self {
+        Self { vec, index: 0 }
+    }
+}
+
+impl<T> TryFromIterator<T> for ZipperOne<T> {
+    type Error = ConstructError<T, 1>;
+
+    /// Creates a `ZipperOne` with the first element selected, returning an error if the iterator is empty.
+    #[inline]
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Self::Error> {
+        VecOne::collect(iter).map(Self::from)
+    }
+}
+
+impl<T> From<ZipperOne<T>> for VecOne<T> {
+    #[inline]
+    fn from(zipper: ZipperOne<T>) -> Self {
+        zipper.vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Overflow, ZipperOne};
+    use crate::{ModifyError, TryFromIterator, vecone};
+
+    #[test]
+    fn zipper_one() {
+        assert!(ZipperOne::new(vecone![1, 2], 2).is_err());
+
+        let mut zipper = ZipperOne::new(vecone![1, 2, 3], 2).unwrap();
+        assert_eq!(zipper.select_next(Overflow::Clamp), &3);
+        assert_eq!(zipper.select_next(Overflow::Wrap), &1);
+        assert_eq!(zipper.select_prev(Overflow::Wrap), &3);
+
+        zipper.insert(0, 0);
+        assert_eq!((zipper.index(), *zipper.selected()), (3, 3));
+
+        assert_eq!(zipper.remove_selected(), Ok(3));
+        assert_eq!(zipper.selected(), &2);
+        assert_eq!(zipper.remove(0), Ok(0));
+        assert_eq!(zipper.selected(), &2);
+
+        zipper.insert_selected(5);
+        let zipper = zipper.map(|x| x * 10);
+        assert_eq!(zipper.selected(), &50);
+
+        assert!(ZipperOne::<()>::try_from_iter([]).is_err());
+        let zipper = ZipperOne::try_from_iter([1, 2]).unwrap();
+        assert_eq!((zipper.index(), zipper.selected()), (0, &1));
+
+        let mut zipper = ZipperOne::singleton('a');
+        assert_eq!(zipper.remove_selected(), Err(ModifyError));
+        assert_eq!(zipper.into_parts(), (vecone!['a'], 0));
+    }
+}